@@ -2,6 +2,7 @@ module.exports = {
   clearMocks: true,
   moduleFileExtensions: ["js", "ts"],
   testMatch: ["**/*.test.ts"],
+  globalSetup: "./tests/setup.ts",
   transform: {
     "^.+\\.ts$": "ts-jest",
   },
@@ -62,6 +62,72 @@ pub enum ErrorCode {
     InvalidMintAuthority,
     #[error("Not enought remaining accounts")]
     NotEnoughRemainingAccounts,
+    #[error("Ruleset is locked")]
+    RulesetLocked,
+    #[error("Mint manager authority has been renounced")]
+    MintManagerAuthorityRenounced,
+    #[error("Attaching to this ruleset requires approval")]
+    RulesetAttachmentNotApproved,
+    #[error("Required creator not verified in mint metadata")]
+    RequiredCreatorNotVerified,
+    #[error("Ruleset requires royalty payment on transfer")]
+    RoyaltyPaymentRequired,
+    #[error("Invalid royalty creator account")]
+    InvalidRoyaltyCreator,
+    #[error("Payment mint not allowed by ruleset")]
+    PaymentMintNotAllowed,
+    #[error("Invalid collection manager")]
+    InvalidCollectionManager,
+    #[error("Mint is not a verified member of the collection")]
+    MintNotInCollection,
+    #[error("Recall is not allowed by ruleset")]
+    RecallNotAllowed,
+    #[error("Recall has been permanently disabled for this mint")]
+    RecallDisabled,
+    #[error("Mint does not delegate recall to the mint manager")]
+    RecallNotSupported,
+    #[error("Token is suspended by the mint manager authority")]
+    TokenSuspended,
+    #[error("Token is not suspended")]
+    TokenNotSuspended,
+    #[error("Suspension is not allowed by ruleset")]
+    SuspensionNotAllowed,
+    #[error("Invalid rental")]
+    InvalidRental,
+    #[error("Token is already rented")]
+    RentalAlreadyRented,
+    #[error("Token is not rented")]
+    RentalNotRented,
+    #[error("Rental has not expired")]
+    RentalNotExpired,
+    #[error("In use until must be in the future")]
+    InvalidInUseUntil,
+    #[error("Too many in use locks")]
+    TooManyInUseLocks,
+    #[error("In use release not allowed")]
+    InUseReleaseNotAllowed,
+    #[error("In use lock not found")]
+    InUseLockNotFound,
+    #[error("In use release already requested")]
+    InUseReleaseAlreadyRequested,
+    #[error("In use release not requested")]
+    InUseReleaseNotRequested,
+    #[error("In use release grace period has not ended")]
+    InUseReleaseGracePeriodActive,
+    #[error("Authority remove in use by not allowed")]
+    AuthorityRemoveInUseByNotAllowed,
+    #[error("Account must be migrated to the current version")]
+    MigrationRequired,
+    #[error("Mint manager belongs to a collection")]
+    MintManagerInCollection,
+    #[error("Collection ruleset cannot require creator basis points or creators")]
+    InvalidCollectionRuleset,
+    #[error("In use record must be initialized")]
+    InUseRecordNotInitialized,
+    #[error("Token cannot be recalled while it is listed or rented")]
+    RecallDuringRental,
+    #[error("Holder token account must hold the entire supply")]
+    HolderDoesNotHoldSupply,
 }
 
 impl PrintProgramError for ErrorCode {
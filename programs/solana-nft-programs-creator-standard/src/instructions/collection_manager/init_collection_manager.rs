@@ -0,0 +1,153 @@
+use crate::errors::ErrorCode;
+use crate::id;
+use crate::state::assert_collection_manager_seeds;
+use crate::state::assert_mint_metadata_seeds;
+use crate::state::assert_ruleset_attachment_approval;
+use crate::state::CollectionManager;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintMetadata;
+use crate::state::Ruleset;
+use crate::state::COLLECTION_MANAGER_SIZE;
+use crate::utils::assert_address;
+use crate::utils::assert_empty;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::unpack_checked_mint_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::create_account;
+use solana_program::system_program;
+use solana_program::sysvar::Sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn init_collection_manager(
+    program_id: Pubkey,
+    collection_manager: Pubkey,
+    collection: Pubkey,
+    collection_metadata: Pubkey,
+    ruleset: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(collection_manager, false),
+            AccountMeta::new_readonly(collection, false),
+            AccountMeta::new_readonly(collection_metadata, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: CreatorStandardInstruction::InitCollectionManager.try_to_vec()?,
+    })
+}
+
+pub struct InitCollectionManagerCtx<'a, 'info> {
+    pub collection_manager: &'a AccountInfo<'info>,
+    pub collection: &'a AccountInfo<'info>,
+    pub collection_metadata: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> InitCollectionManagerCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            collection_manager: next_account_info(account_iter)?,
+            collection: next_account_info(account_iter)?,
+            collection_metadata: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+
+        // collection_manager
+        assert_mut(ctx.collection_manager, "collection_manager")?;
+        assert_empty(ctx.collection_manager, "collection_manager")?;
+
+        // collection
+        unpack_checked_mint_account(ctx.collection, Some("collection"))?;
+
+        // collection_metadata
+        assert_mint_metadata_seeds(ctx.collection.key, ctx.collection_metadata.key)?;
+        let collection_metadata = MintMetadata::from_account_info(ctx.collection_metadata)?;
+        assert_address(
+            &collection_metadata.mint,
+            ctx.collection.key,
+            "collection_metadata mint",
+        )?;
+
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        if &collection_metadata.update_authority != ctx.authority.key {
+            return Err(ProgramError::from(ErrorCode::InvalidAuthority));
+        }
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: InitCollectionManagerCtx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    assert_ruleset_attachment_approval(&ruleset, remaining_accounts)?;
+
+    let collection_manager_space = COLLECTION_MANAGER_SIZE;
+    let collection_manager_seeds =
+        assert_collection_manager_seeds(ctx.collection.key, ctx.collection_manager.key)?;
+    // create collection manager account
+    invoke_signed(
+        &create_account(
+            ctx.payer.key,
+            ctx.collection_manager.key,
+            Rent::get()?.minimum_balance(collection_manager_space),
+            u64::try_from(collection_manager_space).expect("Could not cast to u64"),
+            &id(),
+        ),
+        &[ctx.payer.clone(), ctx.collection_manager.clone()],
+        &[&collection_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    let mut collection_manager: CollectionManager = CollectionManager::new();
+    collection_manager.version = 0;
+    collection_manager.collection = *ctx.collection.key;
+    collection_manager.authority = *ctx.authority.key;
+    collection_manager.ruleset = *ctx.ruleset.key;
+    collection_manager.save(ctx.collection_manager)?;
+
+    Ok(())
+}
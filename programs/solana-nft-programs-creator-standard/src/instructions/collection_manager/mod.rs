@@ -0,0 +1,5 @@
+pub mod init_collection_manager;
+pub mod update_collection_manager;
+
+pub use init_collection_manager::*;
+pub use update_collection_manager::*;
@@ -0,0 +1,105 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_ruleset_attachment_approval;
+use crate::state::CollectionManager;
+use crate::state::CreatorStandardAccount;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_collection_manager(
+    program_id: Pubkey,
+    collection_manager: Pubkey,
+    ruleset: Pubkey,
+    authority: Pubkey,
+    new_authority: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(collection_manager, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: CreatorStandardInstruction::UpdateCollectionManager(UpdateCollectionManagerIx {
+            authority: new_authority,
+        })
+        .try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct UpdateCollectionManagerIx {
+    pub authority: Pubkey,
+}
+
+pub struct UpdateCollectionManagerCtx<'a, 'info> {
+    pub collection_manager: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> UpdateCollectionManagerCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            collection_manager: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let collection_manager: CollectionManager =
+            CollectionManager::from_account_info(ctx.collection_manager)?;
+
+        // collection_manager
+        assert_mut(ctx.collection_manager, "collection_manager")?;
+
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        assert_address(
+            ctx.authority.key,
+            &collection_manager.authority,
+            "authority",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: UpdateCollectionManagerCtx, ix: UpdateCollectionManagerIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let mut collection_manager: CollectionManager =
+        CollectionManager::from_account_info(ctx.collection_manager)?;
+    if collection_manager.ruleset != *ctx.ruleset.key {
+        // existing members were never checked against the creator requirements of the new ruleset
+        if ruleset.minimum_creator_basis_points > 0 || !ruleset.required_creators.is_empty() {
+            return Err(ProgramError::from(ErrorCode::InvalidCollectionRuleset));
+        }
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        assert_ruleset_attachment_approval(&ruleset, remaining_accounts)?;
+    }
+    // every mint manager in the collection resolves its ruleset from here
+    collection_manager.authority = ix.authority;
+    collection_manager.ruleset = *ctx.ruleset.key;
+    collection_manager.save(ctx.collection_manager)?;
+
+    Ok(())
+}
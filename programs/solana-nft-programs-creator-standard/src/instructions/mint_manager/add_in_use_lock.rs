@@ -0,0 +1,209 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_holder_token_amount;
+use crate::state::assert_in_use_by_allowed;
+use crate::state::assert_in_use_until;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::in_use_by;
+use crate::state::in_use_locks;
+use crate::state::is_in_use_expired;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::push_additional_in_use_lock;
+use crate::state::save_in_use_by;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUseLock;
+use crate::state::InUsePurpose;
+use crate::state::MintManager;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+#[allow(clippy::too_many_arguments)]
+pub fn add_in_use_lock(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    ruleset: Pubkey,
+    holder: Pubkey,
+    holder_token_account: Pubkey,
+    in_use_by_address: Pubkey,
+    payer: Pubkey,
+    in_use_until: Option<i64>,
+    purpose: InUsePurpose,
+    reference: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new_readonly(in_use_by_address, false),
+            AccountMeta::new_readonly(holder, true),
+            AccountMeta::new_readonly(holder_token_account, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: CreatorStandardInstruction::AddInUseLock(AddInUseLockIx {
+            in_use_until,
+            purpose,
+            reference,
+        })
+        .try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct AddInUseLockIx {
+    pub in_use_until: Option<i64>,
+    pub purpose: InUsePurpose,
+    pub reference: Option<Pubkey>,
+}
+
+pub struct AddInUseLockCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub in_use_by_address: &'a AccountInfo<'info>,
+    pub holder: &'a AccountInfo<'info>,
+    pub holder_token_account: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> AddInUseLockCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            in_use_by_address: next_account_info(account_iter)?,
+            holder: next_account_info(account_iter)?,
+            holder_token_account: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let holder_token_account =
+            unpack_checked_token_account(ctx.holder_token_account, Some("holder_token_account"))?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
+        ///// no checks for in_use_by_address /////
+
+        // holder
+        assert_signer(ctx.holder, "holder")?;
+
+        // holder_token_account
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
+            "holder_token_account",
+        )?;
+        assert_address(
+            &holder_token_account.owner,
+            ctx.holder.key,
+            "holder_token_account owner",
+        )?;
+        assert_address(
+            &holder_token_account.mint,
+            &mint_manager.mint,
+            "holder_token_account mint",
+        )?;
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: AddInUseLockCtx, ix: AddInUseLockIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
+    assert_in_use_until(ix.in_use_until)?;
+    for lock in in_use_locks(&mint_manager, ctx.in_use_record)? {
+        if lock.in_use_by == *ctx.in_use_by_address.key && !is_in_use_expired(lock.in_use_until)? {
+            return Err(ProgramError::from(ErrorCode::TokenAlreadyInUse));
+        }
+    }
+    // the primary lock is used first so single lock integrations keep working
+    if in_use_by(&mint_manager, ctx.in_use_record)?.is_none() {
+        save_in_use_by(
+            ctx.mint_manager,
+            ctx.in_use_record,
+            Some(*ctx.in_use_by_address.key),
+            ix.in_use_until,
+            ix.purpose,
+            ix.reference,
+        )?;
+    } else {
+        push_additional_in_use_lock(
+            ctx.mint_manager,
+            ctx.in_use_record,
+            InUseLock {
+                in_use_by: *ctx.in_use_by_address.key,
+                in_use_until: ix.in_use_until,
+                release_requested_at: None,
+                purpose: ix.purpose,
+                reference: ix.reference,
+            },
+            ctx.payer,
+            ctx.system_program,
+        )?;
+    }
+    assert_in_use_by_allowed(
+        &ruleset,
+        ctx.in_use_by_address,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use crate::errors::ErrorCode;
+use crate::id;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::in_use_locks;
+use crate::state::next_collection_manager;
+use crate::state::remove_in_use_lock;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUseRecord;
+use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub struct AuthorityRemoveInUseByEntry {
+    pub mint_manager: Pubkey,
+    pub collection_manager: Option<Pubkey>,
+    pub in_use_record: Option<Pubkey>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn authority_remove_in_use_by(
+    program_id: Pubkey,
+    ruleset: Pubkey,
+    in_use_by_address: Pubkey,
+    authority: Pubkey,
+    entries: Vec<AuthorityRemoveInUseByEntry>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new_readonly(in_use_by_address, false),
+        AccountMeta::new_readonly(authority, true),
+    ];
+    for entry in entries {
+        accounts.push(AccountMeta::new(entry.mint_manager, false));
+        if let Some(collection_manager) = entry.collection_manager {
+            accounts.push(AccountMeta::new_readonly(collection_manager, false));
+        }
+        if let Some(in_use_record) = entry.in_use_record {
+            accounts.push(AccountMeta::new(in_use_record, false));
+        }
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::AuthorityRemoveInUseBy.try_to_vec()?,
+    })
+}
+
+pub struct AuthorityRemoveInUseByCtx<'a, 'info> {
+    pub ruleset: &'a AccountInfo<'info>,
+    pub in_use_by_address: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> AuthorityRemoveInUseByCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            ruleset: next_account_info(account_iter)?,
+            in_use_by_address: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        ///// no checks for in_use_by_address /////
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(mut ctx: AuthorityRemoveInUseByCtx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    // locks held by this program (e.g. rentals) have their own release path
+    if !ruleset.allow_authority_remove_in_use_by || ctx.in_use_by_address.owner == &id() {
+        return Err(ProgramError::from(
+            ErrorCode::AuthorityRemoveInUseByNotAllowed,
+        ));
+    }
+    if ctx.remaining_accounts.is_empty() {
+        return Err(ProgramError::from(ErrorCode::NotEnoughRemainingAccounts));
+    }
+
+    // (mint_manager, collection_manager?, in_use_record?) for each mint manager
+    while !ctx.remaining_accounts.is_empty() {
+        let mint_manager_info = ctx.remaining_accounts.remove(0);
+        assert_mut(mint_manager_info, "mint_manager")?;
+        assert_program_account(mint_manager_info, MintManager::hash())?;
+        let mint_manager: MintManager = MintManager::from_account_info(mint_manager_info)?;
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_address(ctx.authority.key, &mint_manager.authority, "authority")?;
+
+        let in_use_record = if mint_manager.kind == MintManagerKind::NonFungible {
+            None
+        } else {
+            if ctx.remaining_accounts.is_empty() {
+                return Err(ProgramError::from(ErrorCode::NotEnoughRemainingAccounts));
+            }
+            let in_use_record_info = ctx.remaining_accounts.remove(0);
+            assert_mut(in_use_record_info, "in_use_record")?;
+            assert_program_account(in_use_record_info, InUseRecord::hash())?;
+            let in_use_record: InUseRecord = InUseRecord::from_account_info(in_use_record_info)?;
+            assert_address(
+                &in_use_record.mint,
+                &mint_manager.mint,
+                "in_use_record mint",
+            )?;
+            Some(in_use_record_info)
+        };
+
+        if !in_use_locks(&mint_manager, in_use_record)?
+            .iter()
+            .any(|lock| &lock.in_use_by == ctx.in_use_by_address.key)
+        {
+            return Err(ProgramError::from(ErrorCode::InUseLockNotFound));
+        }
+        remove_in_use_lock(mint_manager_info, in_use_record, ctx.in_use_by_address.key)?;
+    }
+
+    Ok(())
+}
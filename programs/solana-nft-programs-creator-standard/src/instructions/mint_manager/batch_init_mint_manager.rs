@@ -0,0 +1,166 @@
+use crate::errors::ErrorCode;
+use crate::instructions::mint_manager::init_mint_manager;
+use crate::instructions::mint_manager::InitMintManagerCtx;
+use crate::instructions::mint_manager::InitMintManagerIx;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManagerKind;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_with_msg;
+use crate::utils::is_token_program;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+pub const BATCH_INIT_MINT_MANAGER_ACCOUNTS: usize = 4;
+
+pub struct BatchInitMintManagerEntry {
+    pub mint_manager: Pubkey,
+    pub mint: Pubkey,
+    pub mint_metadata: Pubkey,
+    pub holder_token_account: Pubkey,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn batch_init_mint_manager(
+    program_id: Pubkey,
+    ruleset: Pubkey,
+    token_authority: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    token_program: Pubkey,
+    attachment_approver: Option<Pubkey>,
+    entries: Vec<BatchInitMintManagerEntry>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new_readonly(token_authority, true),
+        AccountMeta::new_readonly(authority, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+    ];
+    if let Some(attachment_approver) = attachment_approver {
+        accounts.push(AccountMeta::new_readonly(attachment_approver, true));
+    }
+    for entry in entries {
+        accounts.push(AccountMeta::new(entry.mint_manager, false));
+        accounts.push(AccountMeta::new(entry.mint, false));
+        accounts.push(AccountMeta::new_readonly(entry.mint_metadata, false));
+        accounts.push(AccountMeta::new(entry.holder_token_account, false));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::BatchInitMintManager.try_to_vec()?,
+    })
+}
+
+pub struct BatchInitMintManagerCtx<'a, 'info> {
+    pub ruleset: &'a AccountInfo<'info>,
+    pub token_authority: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> BatchInitMintManagerCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            ruleset: next_account_info(account_iter)?,
+            token_authority: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // token_authority
+        assert_signer(ctx.token_authority, "token_authority")?;
+
+        ///// no checks for authority /////
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // token_program
+        assert_with_msg(
+            is_token_program(ctx.token_program.key),
+            ProgramError::IncorrectProgramId,
+            "Invalid token_program",
+        )?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: BatchInitMintManagerCtx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let attachment_approver = if ruleset.require_attachment_approval {
+        Some(
+            remaining_accounts
+                .next()
+                .ok_or(ErrorCode::NotEnoughRemainingAccounts)?,
+        )
+    } else {
+        None
+    };
+
+    // (mint_manager, mint, mint_metadata, holder_token_account) for each mint
+    let mint_accounts: Vec<&&AccountInfo> = remaining_accounts.collect();
+    if mint_accounts.is_empty() || mint_accounts.len() % BATCH_INIT_MINT_MANAGER_ACCOUNTS != 0 {
+        return Err(ProgramError::from(ErrorCode::NotEnoughRemainingAccounts));
+    }
+
+    for entry in mint_accounts.chunks(BATCH_INIT_MINT_MANAGER_ACCOUNTS) {
+        let mut accounts = vec![
+            (*entry[0]).clone(),
+            (*entry[1]).clone(),
+            (*entry[2]).clone(),
+            ctx.ruleset.clone(),
+            (*entry[3]).clone(),
+            ctx.token_authority.clone(),
+            ctx.authority.clone(),
+            ctx.payer.clone(),
+            ctx.token_program.clone(),
+            ctx.system_program.clone(),
+        ];
+        if let Some(attachment_approver) = attachment_approver {
+            accounts.push((*attachment_approver).clone());
+        }
+        let init_ctx = InitMintManagerCtx::load(&accounts)?;
+        init_mint_manager::handler(
+            init_ctx,
+            InitMintManagerIx {
+                kind: MintManagerKind::NonFungible,
+            },
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,11 +1,17 @@
 use crate::errors::ErrorCode;
 use crate::state::assert_mint_manager_seeds;
+use crate::state::close_in_use_record;
+use crate::state::is_token_in_use;
+use crate::state::next_in_use_record;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
+use crate::state::MintManagerKind;
 use crate::utils::assert_address;
 use crate::utils::assert_mut;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::unpack_checked_mint_account;
+use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
@@ -27,20 +33,26 @@ pub fn close_mint_manager(
     new_token_authority: Pubkey,
     authority: Pubkey,
     payer: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(holder_token_account, false),
+        AccountMeta::new_readonly(new_token_authority, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new(mint_manager, false),
-            AccountMeta::new(mint, false),
-            AccountMeta::new(holder_token_account, false),
-            AccountMeta::new_readonly(new_token_authority, false),
-            AccountMeta::new_readonly(authority, true),
-            AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-        ],
-        data: CreatorStandardInstruction::InitMintManager.try_to_vec()?,
+        accounts,
+        data: CreatorStandardInstruction::CloseMintManager.try_to_vec()?,
     })
 }
 
@@ -53,12 +65,14 @@ pub struct CloseMintManagerCtx<'a, 'info> {
     pub payer: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
     pub system_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> CloseMintManagerCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
             holder_token_account: next_account_info(account_iter)?,
@@ -67,11 +81,16 @@ impl<'a, 'info> CloseMintManagerCtx<'a, 'info> {
             payer: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
             system_program: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
         };
         // deserializations
         let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
-        if mint_manager.in_use_by.is_some() {
-            return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
+        let mint = unpack_checked_mint_account(ctx.mint, Some("token mint"))?;
+        let holder_token_account =
+            unpack_checked_token_account(ctx.holder_token_account, Some("holder_token_account"))?;
+        if mint_manager.authority_renounced {
+            return Err(ProgramError::from(ErrorCode::MintManagerAuthorityRenounced));
         }
 
         // mint_manager
@@ -80,7 +99,34 @@ impl<'a, 'info> CloseMintManagerCtx<'a, 'info> {
         // check valid mint
         assert_mut(ctx.mint, "mint")?;
         assert_address(ctx.mint.key, &mint_manager.mint, "mint")?;
-        unpack_checked_mint_account(ctx.mint, Some("token mint"))?;
+
+        // holder_token_account
+        assert_mut(ctx.holder_token_account, "holder_token_account")?;
+        assert_address(
+            &holder_token_account.mint,
+            ctx.mint.key,
+            "holder_token_account mint",
+        )?;
+        // active locks only exist on accounts holding tokens, so when this account holds
+        // the entire supply its in use state is the only one left to check
+        if mint_manager.kind != MintManagerKind::NonFungible
+            && holder_token_account.amount != mint.supply
+        {
+            return Err(ProgramError::from(ErrorCode::HolderDoesNotHoldSupply));
+        }
+
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+        if is_token_in_use(&mint_manager, ctx.in_use_record)? {
+            return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
+        }
 
         // no checks for new token authority
 
@@ -97,7 +143,7 @@ impl<'a, 'info> CloseMintManagerCtx<'a, 'info> {
         assert_mut(ctx.payer, "payer")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         // system_program
         assert_address(
@@ -115,7 +161,7 @@ pub fn handler(ctx: CloseMintManagerCtx) -> ProgramResult {
 
     // thaw account
     invoke_signed(
-        &spl_token::instruction::thaw_account(
+        &spl_token_2022::instruction::thaw_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -135,11 +181,11 @@ pub fn handler(ctx: CloseMintManagerCtx) -> ProgramResult {
 
     // set mint authority
     invoke_signed(
-        &spl_token::instruction::set_authority(
+        &spl_token_2022::instruction::set_authority(
             ctx.token_program.key,
             ctx.mint.key,
             Some(ctx.new_token_authority.key),
-            spl_token::instruction::AuthorityType::MintTokens,
+            spl_token_2022::instruction::AuthorityType::MintTokens,
             ctx.mint_manager.key,
             &[],
         )?,
@@ -156,11 +202,11 @@ pub fn handler(ctx: CloseMintManagerCtx) -> ProgramResult {
 
     // set freeze authoriy
     invoke_signed(
-        &spl_token::instruction::set_authority(
+        &spl_token_2022::instruction::set_authority(
             ctx.token_program.key,
             ctx.mint.key,
             Some(ctx.new_token_authority.key),
-            spl_token::instruction::AuthorityType::FreezeAccount,
+            spl_token_2022::instruction::AuthorityType::FreezeAccount,
             ctx.mint_manager.key,
             &[],
         )?,
@@ -175,6 +221,8 @@ pub fn handler(ctx: CloseMintManagerCtx) -> ProgramResult {
             .collect::<Vec<&[u8]>>()],
     )?;
 
+    close_in_use_record(ctx.in_use_record, ctx.authority)?;
+
     let destination_starting_lamports = ctx.authority.lamports();
     **ctx.authority.lamports.borrow_mut() = destination_starting_lamports
         .checked_add(ctx.mint_manager.lamports())
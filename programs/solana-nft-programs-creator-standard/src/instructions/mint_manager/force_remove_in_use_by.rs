@@ -0,0 +1,147 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_holder_token_amount;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::in_use_locks;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::remove_in_use_lock;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn force_remove_in_use_by(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    ruleset: Pubkey,
+    holder: Pubkey,
+    holder_token_account: Pubkey,
+    in_use_by_address: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new_readonly(in_use_by_address, false),
+            AccountMeta::new_readonly(holder, true),
+            AccountMeta::new_readonly(holder_token_account, false),
+        ],
+        data: CreatorStandardInstruction::ForceRemoveInUseBy.try_to_vec()?,
+    })
+}
+
+pub struct ForceRemoveInUseByCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub in_use_by_address: &'a AccountInfo<'info>,
+    pub holder: &'a AccountInfo<'info>,
+    pub holder_token_account: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> ForceRemoveInUseByCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            in_use_by_address: next_account_info(account_iter)?,
+            holder: next_account_info(account_iter)?,
+            holder_token_account: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let holder_token_account =
+            unpack_checked_token_account(ctx.holder_token_account, Some("holder_token_account"))?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
+        ///// no checks for in_use_by_address /////
+
+        // holder
+        assert_signer(ctx.holder, "holder")?;
+
+        // holder_token_account
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
+            "holder_token_account",
+        )?;
+        assert_address(
+            &holder_token_account.owner,
+            ctx.holder.key,
+            "holder_token_account owner",
+        )?;
+        assert_address(
+            &holder_token_account.mint,
+            &mint_manager.mint,
+            "holder_token_account mint",
+        )?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: ForceRemoveInUseByCtx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    let grace_period_seconds = ruleset
+        .in_use_release_grace_period_seconds
+        .ok_or(ErrorCode::InUseReleaseNotAllowed)?;
+    let lock = in_use_locks(&mint_manager, ctx.in_use_record)?
+        .into_iter()
+        .find(|lock| &lock.in_use_by == ctx.in_use_by_address.key)
+        .ok_or(ErrorCode::InUseLockNotFound)?;
+    // a renewal by the in use address clears the request
+    let release_requested_at = lock
+        .release_requested_at
+        .ok_or(ErrorCode::InUseReleaseNotRequested)?;
+    if Clock::get()?.unix_timestamp < release_requested_at.saturating_add(grace_period_seconds) {
+        return Err(ProgramError::from(ErrorCode::InUseReleaseGracePeriodActive));
+    }
+
+    remove_in_use_lock(
+        ctx.mint_manager,
+        ctx.in_use_record,
+        ctx.in_use_by_address.key,
+    )?;
+
+    Ok(())
+}
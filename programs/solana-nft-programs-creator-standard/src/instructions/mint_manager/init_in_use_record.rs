@@ -0,0 +1,132 @@
+use crate::errors::ErrorCode;
+use crate::id;
+use crate::state::assert_in_use_record_seeds;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUseRecord;
+use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::state::IN_USE_RECORD_SIZE;
+use crate::utils::assert_address;
+use crate::utils::assert_empty;
+use crate::utils::assert_mut;
+use crate::utils::assert_signer;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::create_account;
+use solana_program::system_program;
+use solana_program::sysvar::Sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn init_in_use_record(
+    program_id: Pubkey,
+    in_use_record: Pubkey,
+    mint_manager: Pubkey,
+    holder_token_account: Pubkey,
+    payer: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(in_use_record, false),
+            AccountMeta::new_readonly(mint_manager, false),
+            AccountMeta::new_readonly(holder_token_account, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: CreatorStandardInstruction::InitInUseRecord.try_to_vec()?,
+    })
+}
+
+pub struct InitInUseRecordCtx<'a, 'info> {
+    pub in_use_record: &'a AccountInfo<'info>,
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub holder_token_account: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> InitInUseRecordCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            in_use_record: next_account_info(account_iter)?,
+            mint_manager: next_account_info(account_iter)?,
+            holder_token_account: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let holder_token_account =
+            unpack_checked_token_account(ctx.holder_token_account, Some("holder_token_account"))?;
+
+        // in_use_record
+        assert_mut(ctx.in_use_record, "in_use_record")?;
+        assert_empty(ctx.in_use_record, "in_use_record")?;
+
+        // mint_manager
+        if mint_manager.kind == MintManagerKind::NonFungible {
+            return Err(ProgramError::from(ErrorCode::InvalidMintManager));
+        }
+
+        // holder_token_account
+        assert_address(
+            &holder_token_account.mint,
+            &mint_manager.mint,
+            "holder_token_account mint",
+        )?;
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: InitInUseRecordCtx) -> ProgramResult {
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    let in_use_record_space = IN_USE_RECORD_SIZE;
+    let in_use_record_seeds =
+        assert_in_use_record_seeds(ctx.holder_token_account.key, ctx.in_use_record.key)?;
+    // create in use record account
+    invoke_signed(
+        &create_account(
+            ctx.payer.key,
+            ctx.in_use_record.key,
+            Rent::get()?.minimum_balance(in_use_record_space),
+            u64::try_from(in_use_record_space).expect("Could not cast to u64"),
+            &id(),
+        ),
+        &[ctx.payer.clone(), ctx.in_use_record.clone()],
+        &[&in_use_record_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    let mut in_use_record: InUseRecord = InUseRecord::new();
+    in_use_record.version = 0;
+    in_use_record.mint = mint_manager.mint;
+    in_use_record.holder_token_account = *ctx.holder_token_account.key;
+    in_use_record.in_use_by = None;
+    in_use_record.save(ctx.in_use_record)?;
+
+    Ok(())
+}
@@ -1,17 +1,28 @@
 use crate::errors::ErrorCode;
 use crate::id;
 use crate::state::assert_mint_manager_seeds;
+use crate::state::assert_mint_metadata_creator_share;
+use crate::state::assert_mint_metadata_seeds;
+use crate::state::assert_ruleset_attachment_approval;
+use crate::state::is_collection_manager_account;
+use crate::state::CollectionManager;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::state::MintMetadata;
+use crate::state::Ruleset;
 use crate::state::MINT_MANAGER_SIZE;
+use crate::state::MINT_MANAGER_VERSION;
 use crate::utils::assert_address;
-use crate::utils::assert_amount;
 use crate::utils::assert_empty;
 use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::unpack_checked_mint_account;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
 use solana_program::account_info::AccountInfo;
@@ -38,25 +49,40 @@ pub fn init_mint_manager(
     token_authority: Pubkey,
     authority: Pubkey,
     payer: Pubkey,
+    token_program: Pubkey,
+    collection_manager: Option<Pubkey>,
+    kind: MintManagerKind,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(mint_metadata, false),
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new(holder_token_account, false),
+        AccountMeta::new_readonly(token_authority, true),
+        AccountMeta::new_readonly(authority, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+    ];
+    if let Some(collection_manager) = collection_manager {
+        accounts.push(AccountMeta::new_readonly(collection_manager, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new(mint_manager, false),
-            AccountMeta::new(mint, false),
-            AccountMeta::new_readonly(mint_metadata, false),
-            AccountMeta::new_readonly(ruleset, false),
-            AccountMeta::new(holder_token_account, false),
-            AccountMeta::new_readonly(token_authority, true),
-            AccountMeta::new_readonly(authority, false),
-            AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-        ],
-        data: CreatorStandardInstruction::InitMintManager.try_to_vec()?,
+        accounts,
+        data: CreatorStandardInstruction::InitMintManager(InitMintManagerIx { kind })
+            .try_to_vec()?,
     })
 }
 
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct InitMintManagerIx {
+    pub kind: MintManagerKind,
+}
+
 pub struct InitMintManagerCtx<'a, 'info> {
     pub mint_manager: &'a AccountInfo<'info>,
     pub mint: &'a AccountInfo<'info>,
@@ -68,12 +94,14 @@ pub struct InitMintManagerCtx<'a, 'info> {
     pub payer: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
     pub system_program: &'a AccountInfo<'info>,
+    pub collection_manager: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> InitMintManagerCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
             mint_metadata: next_account_info(account_iter)?,
@@ -84,6 +112,8 @@ impl<'a, 'info> InitMintManagerCtx<'a, 'info> {
             payer: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
             system_program: next_account_info(account_iter)?,
+            collection_manager: None,
+            remaining_accounts: account_iter.collect(),
         };
         // deserializations
         let holder_token_account =
@@ -97,15 +127,32 @@ impl<'a, 'info> InitMintManagerCtx<'a, 'info> {
         assert_mut(ctx.mint, "mint")?;
         unpack_checked_mint_account(ctx.mint, Some("token mint"))?;
 
-        ///// no checks for ruleset /////
+        // mint_metadata
+        assert_mint_metadata_seeds(ctx.mint.key, ctx.mint_metadata.key)?;
+        let mint_metadata = MintMetadata::from_account_info(ctx.mint_metadata)?;
+        assert_address(&mint_metadata.mint, ctx.mint.key, "mint_metadata mint")?;
+
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // collection_manager
+        if ctx
+            .remaining_accounts
+            .first()
+            .map_or(false, |account| is_collection_manager_account(account))
+        {
+            let collection_manager_info = ctx.remaining_accounts.remove(0);
+            let collection_manager: CollectionManager =
+                CollectionManager::from_account_info(collection_manager_info)?;
+            assert_address(&collection_manager.ruleset, ctx.ruleset.key, "ruleset")?;
+            if !mint_metadata.is_verified_collection_member(&collection_manager.collection) {
+                return Err(ProgramError::from(ErrorCode::MintNotInCollection));
+            }
+            ctx.collection_manager = Some(collection_manager_info);
+        }
 
         // holder_token_account
         assert_mut(ctx.holder_token_account, "holder_token_account")?;
-        assert_amount(
-            &holder_token_account.amount.to_string(),
-            "1",
-            "holder_token_account",
-        )?;
         assert_address(
             &holder_token_account.mint,
             ctx.mint.key,
@@ -114,6 +161,9 @@ impl<'a, 'info> InitMintManagerCtx<'a, 'info> {
 
         // token_authority
         assert_signer(ctx.token_authority, "token_authority")?;
+        if !mint_metadata.is_update_authority_or_verified_creator(ctx.token_authority.key) {
+            return Err(ProgramError::from(ErrorCode::InvalidAuthority));
+        }
 
         ///// no checks for authority, potentially they are also signer that is why leaving here and not passing as ix /////
 
@@ -122,7 +172,7 @@ impl<'a, 'info> InitMintManagerCtx<'a, 'info> {
         assert_mut(ctx.payer, "payer")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         // system_program
         assert_address(
@@ -134,19 +184,28 @@ impl<'a, 'info> InitMintManagerCtx<'a, 'info> {
     }
 }
 
-pub fn handler(ctx: InitMintManagerCtx) -> ProgramResult {
+// creates the mint manager account and returns it unsaved along with its signer seeds
+pub fn create_mint_manager<'info>(
+    mint_manager_info: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    ruleset: &AccountInfo<'info>,
+    collection_manager: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    kind: MintManagerKind,
+) -> Result<(MintManager, Vec<Vec<u8>>), ProgramError> {
     let mint_manager_space = MINT_MANAGER_SIZE;
-    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+    let mint_manager_seeds = assert_mint_manager_seeds(mint.key, mint_manager_info.key)?;
     // create mint manager account
     invoke_signed(
         &create_account(
-            ctx.payer.key,
-            ctx.mint_manager.key,
+            payer.key,
+            mint_manager_info.key,
             Rent::get()?.minimum_balance(mint_manager_space),
             u64::try_from(mint_manager_space).expect("Could not cast to u64"),
             &id(),
         ),
-        &[ctx.payer.clone(), ctx.mint_manager.clone()],
+        &[payer.clone(), mint_manager_info.clone()],
         &[&mint_manager_seeds
             .iter()
             .map(|s| s.as_slice())
@@ -154,16 +213,58 @@ pub fn handler(ctx: InitMintManagerCtx) -> ProgramResult {
     )?;
 
     let mut mint_manager: MintManager = MintManager::new();
-    mint_manager.version = 0;
-    mint_manager.mint = *ctx.mint.key;
-    mint_manager.authority = *ctx.authority.key;
-    mint_manager.ruleset = *ctx.ruleset.key;
+    mint_manager.version = MINT_MANAGER_VERSION;
+    mint_manager.mint = *mint.key;
+    mint_manager.authority = *authority.key;
+    mint_manager.ruleset = *ruleset.key;
     mint_manager.in_use_by = None;
+    mint_manager.collection_manager = collection_manager.map(|account| *account.key);
+    mint_manager.kind = kind;
+    Ok((mint_manager, mint_manager_seeds))
+}
+
+pub fn handler(ctx: InitMintManagerCtx, ix: InitMintManagerIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    assert_ruleset_attachment_approval(&ruleset, remaining_accounts)?;
+    let mint_metadata = MintMetadata::from_account_info(ctx.mint_metadata)?;
+    assert_mint_metadata_creator_share(&ruleset, &mint_metadata)?;
+
+    let (mint_manager, mint_manager_seeds) = create_mint_manager(
+        ctx.mint_manager,
+        ctx.mint,
+        ctx.authority,
+        ctx.ruleset,
+        ctx.collection_manager,
+        ctx.payer,
+        ix.kind,
+    )?;
 
     let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    let holder_token_account =
+        unpack_checked_token_account(ctx.holder_token_account, Some("holder_token_account"))?;
+
+    match ix.kind {
+        MintManagerKind::NonFungible => {
+            if mint.supply != 1 || mint.decimals != 0 {
+                return Err(ProgramError::from(ErrorCode::InvalidMint));
+            }
+        }
+        MintManagerKind::SemiFungible => {
+            if mint.supply == 0 || mint.decimals != 0 {
+                return Err(ProgramError::from(ErrorCode::InvalidMint));
+            }
+        }
+        MintManagerKind::Fungible => {
+            if mint.supply == 0 {
+                return Err(ProgramError::from(ErrorCode::InvalidMint));
+            }
+        }
+    }
 
-    if mint.supply != 1 || mint.decimals != 0 {
-        return Err(ProgramError::from(ErrorCode::InvalidMint));
+    // the whole supply must sit in the holder token account so that every holder is frozen
+    if holder_token_account.amount != mint.supply {
+        return Err(ProgramError::from(ErrorCode::InvalidHolderTokenAccount));
     }
 
     // token_authority checks
@@ -178,11 +279,11 @@ pub fn handler(ctx: InitMintManagerCtx) -> ProgramResult {
 
     // set mint authority
     invoke(
-        &spl_token::instruction::set_authority(
+        &spl_token_2022::instruction::set_authority(
             ctx.token_program.key,
             ctx.mint.key,
             Some(ctx.mint_manager.key),
-            spl_token::instruction::AuthorityType::MintTokens,
+            spl_token_2022::instruction::AuthorityType::MintTokens,
             ctx.token_authority.key,
             &[],
         )?,
@@ -191,11 +292,11 @@ pub fn handler(ctx: InitMintManagerCtx) -> ProgramResult {
 
     // set freeze authoriy
     invoke(
-        &spl_token::instruction::set_authority(
+        &spl_token_2022::instruction::set_authority(
             ctx.token_program.key,
             ctx.mint.key,
             Some(ctx.mint_manager.key),
-            spl_token::instruction::AuthorityType::FreezeAccount,
+            spl_token_2022::instruction::AuthorityType::FreezeAccount,
             ctx.token_authority.key,
             &[],
         )?,
@@ -204,7 +305,7 @@ pub fn handler(ctx: InitMintManagerCtx) -> ProgramResult {
 
     // freeze holder token account
     invoke_signed(
-        &spl_token::instruction::freeze_account(
+        &spl_token_2022::instruction::freeze_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
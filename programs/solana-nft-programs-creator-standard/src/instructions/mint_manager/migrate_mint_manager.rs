@@ -0,0 +1,85 @@
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::MINT_MANAGER_SIZE;
+use crate::state::MINT_MANAGER_VERSION;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::grow_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_mint_manager(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    payer: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: CreatorStandardInstruction::MigrateMintManager.try_to_vec()?,
+    })
+}
+
+pub struct MigrateMintManagerCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MigrateMintManagerCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+        };
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_program_account(ctx.mint_manager, MintManager::hash())?;
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: MigrateMintManagerCtx) -> ProgramResult {
+    // older mint managers decode with defaults for the fields added since, so only space and version change
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    mint_manager.version = MINT_MANAGER_VERSION;
+    grow_account(
+        ctx.mint_manager,
+        MINT_MANAGER_SIZE,
+        ctx.payer,
+        ctx.system_program,
+    )?;
+    mint_manager.save(ctx.mint_manager)?;
+
+    Ok(())
+}
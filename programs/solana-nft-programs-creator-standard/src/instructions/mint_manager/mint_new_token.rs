@@ -0,0 +1,361 @@
+use crate::instructions::mint_manager::init_mint_manager::create_mint_manager;
+use crate::state::assert_mint_metadata_creator_share;
+use crate::state::assert_mint_metadata_seeds;
+use crate::state::assert_ruleset_attachment_approval;
+use crate::state::create_mint_metadata_instruction;
+use crate::state::mpl_token_metadata;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManagerKind;
+use crate::state::MintMetadata;
+use crate::state::MintMetadataCreator;
+use crate::state::MintMetadataData;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_empty;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_with_msg;
+use crate::utils::is_token_program;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::create_account;
+use solana_program::system_program;
+use solana_program::sysvar;
+use solana_program::sysvar::Sysvar;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token_2022::extension::ExtensionType;
+use spl_token_2022::state::Mint;
+
+#[allow(clippy::too_many_arguments)]
+pub fn mint_new_token(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    mint_metadata: Pubkey,
+    ruleset: Pubkey,
+    target_token_account: Pubkey,
+    target: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    token_program: Pubkey,
+    ix: MintNewTokenIx,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new(mint, true),
+            AccountMeta::new(mint_metadata, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new(target_token_account, false),
+            AccountMeta::new_readonly(target, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(mpl_token_metadata::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: CreatorStandardInstruction::MintNewToken(ix).try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MintNewTokenIx {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub permanent_delegate: bool,
+}
+
+pub struct MintNewTokenCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub mint_metadata: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub target_token_account: &'a AccountInfo<'info>,
+    pub target: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> MintNewTokenCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            mint_metadata: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            target_token_account: next_account_info(account_iter)?,
+            target: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            associated_token_program: next_account_info(account_iter)?,
+            token_metadata_program: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            rent: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_empty(ctx.mint_manager, "mint_manager")?;
+
+        // mint
+        assert_signer(ctx.mint, "mint")?;
+        assert_mut(ctx.mint, "mint")?;
+        assert_empty(ctx.mint, "mint")?;
+
+        // mint_metadata
+        assert_mut(ctx.mint_metadata, "mint_metadata")?;
+        assert_empty(ctx.mint_metadata, "mint_metadata")?;
+        assert_mint_metadata_seeds(ctx.mint.key, ctx.mint_metadata.key)?;
+
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // target_token_account
+        assert_mut(ctx.target_token_account, "target_token_account")?;
+        assert_address(
+            ctx.target_token_account.key,
+            &get_associated_token_address_with_program_id(
+                ctx.target.key,
+                ctx.mint.key,
+                ctx.token_program.key,
+            ),
+            "target_token_account",
+        )?;
+
+        ///// no checks for target /////
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // token_program
+        assert_with_msg(
+            is_token_program(ctx.token_program.key),
+            ProgramError::IncorrectProgramId,
+            "Invalid token_program",
+        )?;
+
+        // associated_token_program
+        assert_address(
+            ctx.associated_token_program.key,
+            &spl_associated_token_account::id(),
+            "associated_token_program",
+        )?;
+
+        // token_metadata_program
+        assert_address(
+            ctx.token_metadata_program.key,
+            &mpl_token_metadata::id(),
+            "token_metadata_program",
+        )?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        // rent
+        assert_address(ctx.rent.key, &sysvar::rent::id(), "rent")?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: MintNewTokenCtx, ix: MintNewTokenIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    assert_ruleset_attachment_approval(&ruleset, remaining_accounts)?;
+
+    // recall moves tokens with the permanent delegate which only token 2022 supports
+    if ix.permanent_delegate {
+        assert_address(
+            ctx.token_program.key,
+            &spl_token_2022::id(),
+            "token_program",
+        )?;
+    }
+
+    let (mint_manager, mint_manager_seeds) = create_mint_manager(
+        ctx.mint_manager,
+        ctx.mint,
+        ctx.authority,
+        ctx.ruleset,
+        None,
+        ctx.payer,
+        MintManagerKind::NonFungible,
+    )?;
+
+    // create mint account
+    let mint_space = if ix.permanent_delegate {
+        ExtensionType::get_account_len::<Mint>(&[ExtensionType::PermanentDelegate])
+    } else {
+        ExtensionType::get_account_len::<Mint>(&[])
+    };
+    invoke(
+        &create_account(
+            ctx.payer.key,
+            ctx.mint.key,
+            Rent::get()?.minimum_balance(mint_space),
+            u64::try_from(mint_space).expect("Could not cast to u64"),
+            ctx.token_program.key,
+        ),
+        &[ctx.payer.clone(), ctx.mint.clone()],
+    )?;
+
+    // extensions must be initialized before the mint
+    if ix.permanent_delegate {
+        invoke(
+            &spl_token_2022::instruction::initialize_permanent_delegate(
+                ctx.token_program.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+            )?,
+            &[ctx.mint.clone()],
+        )?;
+    }
+
+    // initialize mint with mint manager as mint and freeze authority
+    invoke(
+        &spl_token_2022::instruction::initialize_mint2(
+            ctx.token_program.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            Some(ctx.mint_manager.key),
+            0,
+        )?,
+        &[ctx.mint.clone()],
+    )?;
+
+    // create mint metadata with authority as update authority and sole verified creator
+    invoke_signed(
+        &create_mint_metadata_instruction(
+            ctx.mint_metadata.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            ctx.payer.key,
+            ctx.authority.key,
+            MintMetadataData {
+                name: ix.name,
+                symbol: ix.symbol,
+                uri: ix.uri,
+                seller_fee_basis_points: ix.seller_fee_basis_points,
+                creators: Some(vec![MintMetadataCreator {
+                    address: *ctx.authority.key,
+                    verified: true,
+                    share: 100,
+                }]),
+            },
+        )?,
+        &[
+            ctx.mint_metadata.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+            ctx.payer.clone(),
+            ctx.authority.clone(),
+            ctx.system_program.clone(),
+            ctx.rent.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+    let mint_metadata = MintMetadata::from_account_info(ctx.mint_metadata)?;
+    assert_mint_metadata_creator_share(&ruleset, &mint_metadata)?;
+
+    // create target token account
+    invoke(
+        &create_associated_token_account(
+            ctx.payer.key,
+            ctx.target.key,
+            ctx.mint.key,
+            ctx.token_program.key,
+        ),
+        &[
+            ctx.payer.clone(),
+            ctx.target_token_account.clone(),
+            ctx.target.clone(),
+            ctx.mint.clone(),
+            ctx.system_program.clone(),
+            ctx.token_program.clone(),
+            ctx.associated_token_program.clone(),
+        ],
+    )?;
+
+    // mint token
+    invoke_signed(
+        &spl_token_2022::instruction::mint_to(
+            ctx.token_program.key,
+            ctx.mint.key,
+            ctx.target_token_account.key,
+            ctx.mint_manager.key,
+            &[],
+            1,
+        )?,
+        &[
+            ctx.mint.clone(),
+            ctx.target_token_account.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // freeze target token account
+    invoke_signed(
+        &spl_token_2022::instruction::freeze_account(
+            ctx.token_program.key,
+            ctx.target_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            &[],
+        )?,
+        &[
+            ctx.target_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    mint_manager.save(ctx.mint_manager)?;
+    Ok(())
+}
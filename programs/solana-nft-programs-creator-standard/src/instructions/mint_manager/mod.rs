@@ -1,11 +1,37 @@
+pub mod add_in_use_lock;
+pub mod authority_remove_in_use_by;
+pub mod batch_init_mint_manager;
 pub mod close_mint_manager;
+pub mod disable_recall;
+pub mod force_remove_in_use_by;
+pub mod init_in_use_record;
 pub mod init_mint_manager;
+pub mod migrate_mint_manager;
+pub mod mint_new_token;
 pub mod remove_in_use_by;
+pub mod renew_in_use_by;
+pub mod renounce_mint_manager_authority;
+pub mod request_in_use_release;
 pub mod set_in_use_by;
+pub mod suspend_token;
+pub mod unsuspend_token;
 pub mod update_mint_manager;
 
+pub use add_in_use_lock::*;
+pub use authority_remove_in_use_by::*;
+pub use batch_init_mint_manager::*;
 pub use close_mint_manager::*;
+pub use disable_recall::*;
+pub use force_remove_in_use_by::*;
+pub use init_in_use_record::*;
 pub use init_mint_manager::*;
+pub use migrate_mint_manager::*;
+pub use mint_new_token::*;
 pub use remove_in_use_by::*;
+pub use renew_in_use_by::*;
+pub use renounce_mint_manager_authority::*;
+pub use request_in_use_release::*;
 pub use set_in_use_by::*;
+pub use suspend_token::*;
+pub use unsuspend_token::*;
 pub use update_mint_manager::*;
@@ -1,5 +1,11 @@
+use crate::errors::ErrorCode;
+use crate::state::in_use_locks;
+use crate::state::is_in_use_expired;
+use crate::state::remove_in_use_lock;
 use crate::state::CreatorStandardAccount;
+use crate::state::InUseRecord;
 use crate::state::MintManager;
+use crate::state::MintManagerKind;
 use crate::utils::assert_address;
 use crate::utils::assert_mut;
 use crate::utils::assert_signer;
@@ -18,13 +24,18 @@ pub fn remove_in_use_by(
     program_id: Pubkey,
     mint_manager: Pubkey,
     user: Pubkey,
+    in_use_record: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new_readonly(user, true),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new(mint_manager, false),
-            AccountMeta::new_readonly(user, true),
-        ],
+        accounts,
         data: CreatorStandardInstruction::RemoveInUseBy.try_to_vec()?,
     })
 }
@@ -32,14 +43,16 @@ pub fn remove_in_use_by(
 pub struct RemoveInUseByCtx<'a, 'info> {
     pub mint_manager: &'a AccountInfo<'info>,
     pub user: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> RemoveInUseByCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             user: next_account_info(account_iter)?,
+            in_use_record: account_iter.next(),
         };
         // deserializations
         let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
@@ -47,22 +60,38 @@ impl<'a, 'info> RemoveInUseByCtx<'a, 'info> {
         // mint_manager
         assert_mut(ctx.mint_manager, "mint_manager")?;
 
+        // in_use_record
+        if mint_manager.kind == MintManagerKind::NonFungible {
+            ctx.in_use_record = None;
+        } else {
+            let in_use_record_info = ctx
+                .in_use_record
+                .ok_or(ErrorCode::NotEnoughRemainingAccounts)?;
+            assert_mut(in_use_record_info, "in_use_record")?;
+            let in_use_record: InUseRecord = InUseRecord::from_account_info(in_use_record_info)?;
+            assert_address(
+                &in_use_record.mint,
+                &mint_manager.mint,
+                "in_use_record mint",
+            )?;
+        }
+
         // user
-        assert_address(
-            &mint_manager.in_use_by.expect("Token not in use"),
-            ctx.user.key,
-            "user",
-        )?;
-        assert_signer(ctx.user, "user")?;
+        let lock = in_use_locks(&mint_manager, ctx.in_use_record)?
+            .into_iter()
+            .find(|lock| &lock.in_use_by == ctx.user.key)
+            .ok_or(ErrorCode::InUseLockNotFound)?;
+        // anyone can clear an expired lock
+        if !is_in_use_expired(lock.in_use_until)? {
+            assert_signer(ctx.user, "user")?;
+        }
 
         Ok(ctx)
     }
 }
 
 pub fn handler(ctx: RemoveInUseByCtx) -> ProgramResult {
-    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
-    mint_manager.in_use_by = None;
-    mint_manager.save(ctx.mint_manager)?;
+    remove_in_use_lock(ctx.mint_manager, ctx.in_use_record, ctx.user.key)?;
 
     Ok(())
 }
@@ -0,0 +1,94 @@
+use crate::errors::ErrorCode;
+use crate::state::in_use_locks;
+use crate::state::save_in_use_release_requested_at;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUseRecord;
+use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn renew_in_use_by(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    user: Pubkey,
+    in_use_record: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new_readonly(user, true),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::RenewInUseBy.try_to_vec()?,
+    })
+}
+
+pub struct RenewInUseByCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub user: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> RenewInUseByCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            user: next_account_info(account_iter)?,
+            in_use_record: account_iter.next(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+
+        // in_use_record
+        if mint_manager.kind == MintManagerKind::NonFungible {
+            ctx.in_use_record = None;
+        } else {
+            let in_use_record_info = ctx
+                .in_use_record
+                .ok_or(ErrorCode::NotEnoughRemainingAccounts)?;
+            assert_mut(in_use_record_info, "in_use_record")?;
+            let in_use_record: InUseRecord = InUseRecord::from_account_info(in_use_record_info)?;
+            assert_address(
+                &in_use_record.mint,
+                &mint_manager.mint,
+                "in_use_record mint",
+            )?;
+        }
+
+        // user
+        in_use_locks(&mint_manager, ctx.in_use_record)?
+            .into_iter()
+            .find(|lock| &lock.in_use_by == ctx.user.key)
+            .ok_or(ErrorCode::InUseLockNotFound)?;
+        assert_signer(ctx.user, "user")?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: RenewInUseByCtx) -> ProgramResult {
+    // the in use address is still responsive so any pending release request is dropped
+    save_in_use_release_requested_at(ctx.mint_manager, ctx.in_use_record, ctx.user.key, None)?;
+
+    Ok(())
+}
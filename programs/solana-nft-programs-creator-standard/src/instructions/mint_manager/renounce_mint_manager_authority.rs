@@ -0,0 +1,95 @@
+use crate::errors::ErrorCode;
+use crate::state::next_collection_manager;
+use crate::state::CollectionManager;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::MINT_MANAGER_VERSION;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn renounce_mint_manager_authority(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    authority: Pubkey,
+    collection_manager: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new_readonly(authority, true),
+    ];
+    if let Some(collection_manager) = collection_manager {
+        accounts.push(AccountMeta::new_readonly(collection_manager, false));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::RenounceMintManagerAuthority.try_to_vec()?,
+    })
+}
+
+pub struct RenounceMintManagerAuthorityCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub collection_manager: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> RenounceMintManagerAuthorityCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            collection_manager: None,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        // older mint managers do not store the renouncement
+        if mint_manager.version < MINT_MANAGER_VERSION {
+            return Err(ProgramError::from(ErrorCode::MigrationRequired));
+        }
+        if mint_manager.authority_renounced {
+            return Err(ProgramError::from(ErrorCode::MintManagerAuthorityRenounced));
+        }
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        assert_address(ctx.authority.key, &mint_manager.authority, "authority")?;
+
+        // collection_manager
+        ctx.collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: RenounceMintManagerAuthorityCtx) -> ProgramResult {
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    // the mint stays bound to its current ruleset from here on, so collection members leave the collection
+    if let Some(collection_manager_info) = ctx.collection_manager {
+        let collection_manager: CollectionManager =
+            CollectionManager::from_account_info(collection_manager_info)?;
+        mint_manager.ruleset = collection_manager.ruleset;
+        mint_manager.collection_manager = None;
+    }
+    mint_manager.authority_renounced = true;
+    mint_manager.save(ctx.mint_manager)?;
+
+    Ok(())
+}
@@ -1,17 +1,26 @@
 use crate::errors::ErrorCode;
-use crate::state::allowlist_disallowlist;
-use crate::state::is_base_program;
+use crate::state::assert_holder_token_amount;
+use crate::state::assert_in_use_by_allowed;
+use crate::state::assert_in_use_until;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::in_use_lock;
+use crate::state::in_use_locks;
+use crate::state::is_in_use_expired;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::save_in_use_by;
 use crate::state::CreatorStandardAccount;
+use crate::state::InUsePurpose;
 use crate::state::MintManager;
 use crate::state::Ruleset;
 use crate::utils::assert_address;
-use crate::utils::assert_amount;
 use crate::utils::assert_mut;
 use crate::utils::assert_program_account;
 use crate::utils::assert_signer;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
 
+use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
 use solana_program::account_info::AccountInfo;
@@ -29,6 +38,9 @@ pub fn set_in_use_by(
     holder: Pubkey,
     holder_token_account: Pubkey,
     in_use_by_address: Pubkey,
+    in_use_until: Option<i64>,
+    purpose: InUsePurpose,
+    reference: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
     Ok(Instruction {
         program_id,
@@ -39,28 +51,44 @@ pub fn set_in_use_by(
             AccountMeta::new_readonly(holder, true),
             AccountMeta::new_readonly(holder_token_account, false),
         ],
-        data: CreatorStandardInstruction::SetInUseBy.try_to_vec()?,
+        data: CreatorStandardInstruction::SetInUseBy(SetInUseByIx {
+            in_use_until,
+            purpose,
+            reference,
+        })
+        .try_to_vec()?,
     })
 }
 
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SetInUseByIx {
+    pub in_use_until: Option<i64>,
+    pub purpose: InUsePurpose,
+    pub reference: Option<Pubkey>,
+}
+
 pub struct SetInUseByCtx<'a, 'info> {
     pub mint_manager: &'a AccountInfo<'info>,
     pub ruleset: &'a AccountInfo<'info>,
     pub in_use_by_address: &'a AccountInfo<'info>,
     pub holder: &'a AccountInfo<'info>,
     pub holder_token_account: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
     pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> SetInUseByCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             ruleset: next_account_info(account_iter)?,
             in_use_by_address: next_account_info(account_iter)?,
             holder: next_account_info(account_iter)?,
             holder_token_account: next_account_info(account_iter)?,
+            in_use_record: None,
             remaining_accounts: account_iter.collect(),
         };
         // deserializations
@@ -72,25 +100,45 @@ impl<'a, 'info> SetInUseByCtx<'a, 'info> {
         assert_mut(ctx.mint_manager, "mint_manager")?;
 
         // ruleset
-        assert_address(&mint_manager.ruleset, ctx.ruleset.key, "ruleset")?;
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
         assert_program_account(ctx.ruleset, Ruleset::hash())?;
 
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
         ///// no checks for in_use_by_address /////
 
         // holder
         assert_signer(ctx.holder, "holder")?;
 
         // holder_token_account
-        assert_amount(
-            &holder_token_account.amount.to_string(),
-            "1",
-            "holder_token_account amount",
-        )?;
-        assert_address(
-            &holder_token_account.owner,
-            ctx.holder.key,
-            "holder_token_account owner",
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
+            "holder_token_account",
         )?;
+        // the current delegate of the holder token account may set in use in place of the owner
+        if holder_token_account.owner != *ctx.holder.key {
+            assert_address(
+                &Option::<Pubkey>::from(holder_token_account.delegate).unwrap_or_default(),
+                ctx.holder.key,
+                "holder_token_account delegate",
+            )?;
+            assert_holder_token_amount(
+                &mint_manager,
+                holder_token_account.delegated_amount,
+                "holder_token_account delegated",
+            )?;
+        }
         assert_address(
             &holder_token_account.mint,
             &mint_manager.mint,
@@ -101,33 +149,37 @@ impl<'a, 'info> SetInUseByCtx<'a, 'info> {
     }
 }
 
-pub fn handler(ctx: SetInUseByCtx) -> ProgramResult {
+pub fn handler(ctx: SetInUseByCtx, ix: SetInUseByIx) -> ProgramResult {
     let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
-    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
-    if mint_manager.in_use_by.is_some() {
-        return Err(ProgramError::from(ErrorCode::TokenAlreadyInUse));
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
     }
-    mint_manager.in_use_by = Some(*ctx.in_use_by_address.key);
-    mint_manager.save(ctx.mint_manager)?;
-    let remaining_accounts = &mut ctx.remaining_accounts.iter();
-
-    /////////////// check allowed / disallowed ///////////////
-    let [allowed_programs, disallowed_addresses] =
-        allowlist_disallowlist(&ruleset, remaining_accounts)?;
-    if !allowed_programs.is_empty()
-        && !is_base_program(ctx.in_use_by_address.owner)
-        && !allowed_programs.contains(&ctx.in_use_by_address.owner.to_string())
-    {
-        return Err(ProgramError::from(ErrorCode::ProgramNotAllowed));
+    // only the primary slot is set here, additional locks are left to AddInUseLock
+    if let Some(lock) = in_use_lock(&mint_manager, ctx.in_use_record)? {
+        if !is_in_use_expired(lock.in_use_until)? {
+            return Err(ProgramError::from(ErrorCode::TokenAlreadyInUse));
+        }
     }
-
-    if !disallowed_addresses.is_empty()
-        && (disallowed_addresses.contains(&ctx.in_use_by_address.owner.to_string())
-            || disallowed_addresses.contains(&ctx.in_use_by_address.key.to_string()))
-    {
-        return Err(ProgramError::from(ErrorCode::AddressDisallowed));
+    for lock in in_use_locks(&mint_manager, ctx.in_use_record)? {
+        if lock.in_use_by == *ctx.in_use_by_address.key && !is_in_use_expired(lock.in_use_until)? {
+            return Err(ProgramError::from(ErrorCode::TokenAlreadyInUse));
+        }
     }
-    ////////////////////////////////////////////////////////////
+    assert_in_use_until(ix.in_use_until)?;
+    save_in_use_by(
+        ctx.mint_manager,
+        ctx.in_use_record,
+        Some(*ctx.in_use_by_address.key),
+        ix.in_use_until,
+        ix.purpose,
+        ix.reference,
+    )?;
+    assert_in_use_by_allowed(
+        &ruleset,
+        ctx.in_use_by_address,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
 
     Ok(())
 }
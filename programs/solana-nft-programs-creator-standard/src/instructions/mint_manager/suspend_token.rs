@@ -0,0 +1,88 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::next_collection_manager;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn suspend_token(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    ruleset: Pubkey,
+    authority: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: CreatorStandardInstruction::SuspendToken.try_to_vec()?,
+    })
+}
+
+pub struct SuspendTokenCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> SuspendTokenCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        if mint_manager.suspended {
+            return Err(ProgramError::from(ErrorCode::TokenSuspended));
+        }
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        assert_address(ctx.authority.key, &mint_manager.authority, "authority")?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: SuspendTokenCtx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    if ruleset.disallow_suspension {
+        return Err(ProgramError::from(ErrorCode::SuspensionNotAllowed));
+    }
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    mint_manager.suspended = true;
+    mint_manager.save(ctx.mint_manager)?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::errors::ErrorCode;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn unsuspend_token(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    authority: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: CreatorStandardInstruction::UnsuspendToken.try_to_vec()?,
+    })
+}
+
+pub struct UnsuspendTokenCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> UnsuspendTokenCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        if !mint_manager.suspended {
+            return Err(ProgramError::from(ErrorCode::TokenNotSuspended));
+        }
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        assert_address(ctx.authority.key, &mint_manager.authority, "authority")?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: UnsuspendTokenCtx) -> ProgramResult {
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    mint_manager.suspended = false;
+    mint_manager.save(ctx.mint_manager)?;
+
+    Ok(())
+}
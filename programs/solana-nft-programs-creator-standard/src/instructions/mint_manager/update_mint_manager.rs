@@ -1,7 +1,14 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_mint_metadata_creator_share;
+use crate::state::assert_mint_metadata_seeds;
+use crate::state::assert_ruleset_attachment_approval;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
+use crate::state::MintMetadata;
+use crate::state::Ruleset;
 use crate::utils::assert_address;
 use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
 use crate::utils::assert_signer;
 use crate::CreatorStandardInstruction;
 use borsh::BorshDeserialize;
@@ -23,6 +30,7 @@ pub fn update_mint_manager(
     authority: Pubkey,
     new_authority: Pubkey,
     payer: Pubkey,
+    mint_metadata: Pubkey,
 ) -> Result<Instruction, ProgramError> {
     Ok(Instruction {
         program_id,
@@ -32,6 +40,7 @@ pub fn update_mint_manager(
             AccountMeta::new_readonly(authority, true),
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(mint_metadata, false),
         ],
         data: CreatorStandardInstruction::UpdateMintManager(UpdateMintManagerIx {
             authority: new_authority,
@@ -53,6 +62,8 @@ pub struct UpdateMintManagerCtx<'a, 'info> {
     pub authority: &'a AccountInfo<'info>,
     pub payer: &'a AccountInfo<'info>,
     pub system_program: &'a AccountInfo<'info>,
+    pub mint_metadata: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> UpdateMintManagerCtx<'a, 'info> {
@@ -64,14 +75,24 @@ impl<'a, 'info> UpdateMintManagerCtx<'a, 'info> {
             authority: next_account_info(account_iter)?,
             payer: next_account_info(account_iter)?,
             system_program: next_account_info(account_iter)?,
+            mint_metadata: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
         };
         // deserializations
         let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        if mint_manager.authority_renounced {
+            return Err(ProgramError::from(ErrorCode::MintManagerAuthorityRenounced));
+        }
+        // collection members follow the ruleset of their collection manager
+        if mint_manager.collection_manager.is_some() {
+            return Err(ProgramError::from(ErrorCode::MintManagerInCollection));
+        }
 
         // mint_manager
         assert_mut(ctx.mint_manager, "mint_manager")?;
 
-        ///// no checks for ruleset /////
+        // ruleset
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
 
         // authority
         assert_signer(ctx.authority, "authority")?;
@@ -87,12 +108,22 @@ impl<'a, 'info> UpdateMintManagerCtx<'a, 'info> {
             &system_program::id(),
             "system_program",
         )?;
+
+        // mint_metadata
+        assert_mint_metadata_seeds(&mint_manager.mint, ctx.mint_metadata.key)?;
         Ok(ctx)
     }
 }
 
 pub fn handler(ctx: UpdateMintManagerCtx, ix: UpdateMintManagerIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
     let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    if mint_manager.ruleset != *ctx.ruleset.key {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        assert_ruleset_attachment_approval(&ruleset, remaining_accounts)?;
+    }
+    let mint_metadata = MintMetadata::from_account_info(ctx.mint_metadata)?;
+    assert_mint_metadata_creator_share(&ruleset, &mint_metadata)?;
     mint_manager.authority = ix.authority;
     mint_manager.ruleset = *ctx.ruleset.key;
     mint_manager.save(ctx.mint_manager)?;
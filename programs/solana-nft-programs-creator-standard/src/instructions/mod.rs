@@ -1,6 +1,12 @@
+pub mod collection_manager;
+pub use collection_manager::*;
+
 pub mod mint_manager;
 pub use mint_manager::*;
 
+pub mod rental;
+pub use rental::*;
+
 pub mod ruleset;
 pub use ruleset::*;
 
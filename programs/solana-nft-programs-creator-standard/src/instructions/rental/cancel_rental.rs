@@ -0,0 +1,180 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_mint_manager_seeds;
+use crate::state::assert_rental_seeds;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUsePurpose;
+use crate::state::MintManager;
+use crate::state::Rental;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+#[allow(clippy::too_many_arguments)]
+pub fn cancel_rental(
+    program_id: Pubkey,
+    rental: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    owner_token_account: Pubkey,
+    owner: Pubkey,
+    token_program: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(rental, false),
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(owner_token_account, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+        data: CreatorStandardInstruction::CancelRental.try_to_vec()?,
+    })
+}
+
+pub struct CancelRentalCtx<'a, 'info> {
+    pub rental: &'a AccountInfo<'info>,
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub owner_token_account: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CancelRentalCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            rental: next_account_info(account_iter)?,
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            owner_token_account: next_account_info(account_iter)?,
+            owner: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+        };
+        // deserializations
+        let rental: Rental = Rental::from_account_info(ctx.rental)?;
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        if rental.renter.is_some() {
+            return Err(ProgramError::from(ErrorCode::RentalAlreadyRented));
+        }
+
+        // rental
+        assert_mut(ctx.rental, "rental")?;
+        assert_rental_seeds(ctx.mint.key, ctx.rental.key)?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+
+        ///// no checks for mint /////
+
+        // owner_token_account
+        assert_mut(ctx.owner_token_account, "owner_token_account")?;
+        assert_address(
+            ctx.owner_token_account.key,
+            &rental.owner_token_account,
+            "owner_token_account",
+        )?;
+
+        // owner
+        assert_signer(ctx.owner, "owner")?;
+        assert_mut(ctx.owner, "owner")?;
+        assert_address(ctx.owner.key, &rental.owner, "owner")?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: CancelRentalCtx) -> ProgramResult {
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+
+    // thaw account
+    invoke_signed(
+        &spl_token_2022::instruction::thaw_account(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            &[],
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // revoke mint manager approval
+    invoke(
+        &spl_token_2022::instruction::revoke(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.owner.key,
+            &[],
+        )?,
+        &[ctx.owner_token_account.clone(), ctx.owner.clone()],
+    )?;
+
+    // freeze account
+    invoke_signed(
+        &spl_token_2022::instruction::freeze_account(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            &[],
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    mint_manager.in_use_by = None;
+    mint_manager.in_use_until = None;
+    mint_manager.in_use_release_requested_at = None;
+    mint_manager.in_use_purpose = InUsePurpose::Unspecified;
+    mint_manager.in_use_reference = None;
+    mint_manager.save(ctx.mint_manager)?;
+
+    let destination_starting_lamports = ctx.owner.lamports();
+    **ctx.owner.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(ctx.rental.lamports())
+        .expect("Add error");
+    **ctx.rental.lamports.borrow_mut() = 0;
+
+    ctx.rental.assign(&system_program::id());
+    ctx.rental
+        .realloc(0, false)
+        .expect("Error reallocating account");
+
+    Ok(())
+}
@@ -0,0 +1,302 @@
+use crate::errors::ErrorCode;
+use crate::id;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::assert_mint_manager_seeds;
+use crate::state::assert_rental_seeds;
+use crate::state::assert_transfer_instructions;
+use crate::state::is_token_in_use;
+use crate::state::next_collection_manager;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUsePurpose;
+use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::state::Rental;
+use crate::state::Ruleset;
+use crate::state::RENTAL_SIZE;
+use crate::utils::assert_address;
+use crate::utils::assert_empty;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::utils::assert_with_msg;
+use crate::utils::unpack_checked_mint_account;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use lazy_format::lazy_format;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::create_account;
+use solana_program::system_program;
+use solana_program::sysvar;
+use solana_program::sysvar::Sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn list_rental(
+    program_id: Pubkey,
+    rental: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    ruleset: Pubkey,
+    owner_token_account: Pubkey,
+    owner: Pubkey,
+    token_program: Pubkey,
+    price: u64,
+    duration_seconds: i64,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(rental, false),
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new(owner_token_account, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        ],
+        data: CreatorStandardInstruction::ListRental(ListRentalIx {
+            price,
+            duration_seconds,
+        })
+        .try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ListRentalIx {
+    pub price: u64,
+    pub duration_seconds: i64,
+}
+
+pub struct ListRentalCtx<'a, 'info> {
+    pub rental: &'a AccountInfo<'info>,
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub owner_token_account: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub instructions: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> ListRentalCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            rental: next_account_info(account_iter)?,
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            owner_token_account: next_account_info(account_iter)?,
+            owner: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            instructions: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let owner_token_account =
+            unpack_checked_token_account(ctx.owner_token_account, Some("owner_token_account"))?;
+
+        // rental
+        assert_mut(ctx.rental, "rental")?;
+        assert_empty(ctx.rental, "rental")?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+        if mint_manager.kind != MintManagerKind::NonFungible {
+            return Err(ProgramError::from(ErrorCode::InvalidMintManager));
+        }
+
+        ///// no checks for mint /////
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // owner_token_account
+        assert_mut(ctx.owner_token_account, "owner_token_account")?;
+        assert_address(
+            &owner_token_account.mint,
+            ctx.mint.key,
+            "owner_token_account mint",
+        )?;
+        assert_address(
+            &owner_token_account.owner,
+            ctx.owner.key,
+            "owner_token_account owner",
+        )?;
+        assert_with_msg(
+            owner_token_account.amount == 1,
+            ProgramError::InvalidInstructionData,
+            "owner_token_account must hold the token",
+        )?;
+        assert_with_msg(
+            owner_token_account.delegate.is_none(),
+            ProgramError::InvalidInstructionData,
+            lazy_format!("{} must be none", "owner_token_account delegate"),
+        )?;
+
+        // owner
+        assert_signer(ctx.owner, "owner")?;
+        assert_mut(ctx.owner, "owner")?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        // instructions
+        assert_address(
+            ctx.instructions.key,
+            &sysvar::instructions::id(),
+            "instructions",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: ListRentalCtx, ix: ListRentalIx) -> ProgramResult {
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
+    // rentals move the token without a sale so they are held to the same rules as a plain transfer
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    if ruleset.require_royalty_payment {
+        return Err(ProgramError::from(ErrorCode::RoyaltyPaymentRequired));
+    }
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    assert_transfer_instructions(&ruleset, ctx.instructions, remaining_accounts)?;
+    if is_token_in_use(&mint_manager, None)? {
+        return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
+    }
+    if ix.duration_seconds <= 0 {
+        return Err(ProgramError::from(ErrorCode::InvalidRental));
+    }
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+
+    let rental_space = RENTAL_SIZE;
+    let rental_seeds = assert_rental_seeds(ctx.mint.key, ctx.rental.key)?;
+    // create rental account
+    invoke_signed(
+        &create_account(
+            ctx.owner.key,
+            ctx.rental.key,
+            Rent::get()?.minimum_balance(rental_space),
+            u64::try_from(rental_space).expect("Could not cast to u64"),
+            &id(),
+        ),
+        &[ctx.owner.clone(), ctx.rental.clone()],
+        &[&rental_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // thaw account
+    invoke_signed(
+        &spl_token_2022::instruction::thaw_account(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            &[],
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // approve mint manager so the token can be handed to the renter
+    invoke(
+        &spl_token_2022::instruction::approve_checked(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            ctx.owner.key,
+            &[],
+            1,
+            mint.decimals,
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+            ctx.owner.clone(),
+        ],
+    )?;
+
+    // freeze account
+    invoke_signed(
+        &spl_token_2022::instruction::freeze_account(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            &[],
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    let mut rental: Rental = Rental::new();
+    rental.version = 0;
+    rental.mint = *ctx.mint.key;
+    rental.owner = *ctx.owner.key;
+    rental.owner_token_account = *ctx.owner_token_account.key;
+    rental.price = ix.price;
+    rental.duration_seconds = ix.duration_seconds;
+    rental.save(ctx.rental)?;
+
+    // the listed token stays in use by the rental until it is cancelled or reclaimed
+    mint_manager.in_use_by = Some(*ctx.rental.key);
+    mint_manager.in_use_until = None;
+    mint_manager.in_use_release_requested_at = None;
+    mint_manager.in_use_purpose = InUsePurpose::Listed;
+    mint_manager.in_use_reference = None;
+    mint_manager.save(ctx.mint_manager)?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod cancel_rental;
+pub mod list_rental;
+pub mod reclaim_rental;
+pub mod rent_token;
+
+pub use cancel_rental::*;
+pub use list_rental::*;
+pub use reclaim_rental::*;
+pub use rent_token::*;
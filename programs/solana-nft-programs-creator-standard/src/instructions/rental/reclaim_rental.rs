@@ -0,0 +1,215 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_mint_manager_seeds;
+use crate::state::assert_rental_seeds;
+use crate::state::remove_in_use_lock;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::Rental;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_token_program;
+use crate::utils::unpack_checked_mint_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+use solana_program::sysvar::Sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn reclaim_rental(
+    program_id: Pubkey,
+    rental: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    owner_token_account: Pubkey,
+    renter_token_account: Pubkey,
+    owner: Pubkey,
+    token_program: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(rental, false),
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(owner_token_account, false),
+            AccountMeta::new(renter_token_account, false),
+            AccountMeta::new(owner, false),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+        data: CreatorStandardInstruction::ReclaimRental.try_to_vec()?,
+    })
+}
+
+pub struct ReclaimRentalCtx<'a, 'info> {
+    pub rental: &'a AccountInfo<'info>,
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub owner_token_account: &'a AccountInfo<'info>,
+    pub renter_token_account: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ReclaimRentalCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            rental: next_account_info(account_iter)?,
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            owner_token_account: next_account_info(account_iter)?,
+            renter_token_account: next_account_info(account_iter)?,
+            owner: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+        };
+        // deserializations
+        let rental: Rental = Rental::from_account_info(ctx.rental)?;
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let (renter_token_account, expiration) =
+            match (rental.renter_token_account, rental.expiration) {
+                (Some(renter_token_account), Some(expiration)) => {
+                    (renter_token_account, expiration)
+                }
+                _ => return Err(ProgramError::from(ErrorCode::RentalNotRented)),
+            };
+        if Clock::get()?.unix_timestamp < expiration {
+            return Err(ProgramError::from(ErrorCode::RentalNotExpired));
+        }
+
+        // rental
+        assert_mut(ctx.rental, "rental")?;
+        assert_rental_seeds(ctx.mint.key, ctx.rental.key)?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+        if mint_manager.in_use_by != Some(*ctx.rental.key) {
+            return Err(ProgramError::from(ErrorCode::InUseLockNotFound));
+        }
+
+        ///// no checks for mint /////
+
+        // owner_token_account
+        assert_mut(ctx.owner_token_account, "owner_token_account")?;
+        assert_address(
+            ctx.owner_token_account.key,
+            &rental.owner_token_account,
+            "owner_token_account",
+        )?;
+
+        // renter_token_account
+        assert_mut(ctx.renter_token_account, "renter_token_account")?;
+        assert_address(
+            ctx.renter_token_account.key,
+            &renter_token_account,
+            "renter_token_account",
+        )?;
+
+        // owner
+        assert_mut(ctx.owner, "owner")?;
+        assert_address(ctx.owner.key, &rental.owner, "owner")?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: ReclaimRentalCtx) -> ProgramResult {
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+
+    // thaw accounts
+    for token_account_info in [ctx.renter_token_account, ctx.owner_token_account] {
+        invoke_signed(
+            &spl_token_2022::instruction::thaw_account(
+                ctx.token_program.key,
+                token_account_info.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
+    // transfer back to owner as delegate
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            ctx.token_program.key,
+            ctx.renter_token_account.key,
+            ctx.mint.key,
+            ctx.owner_token_account.key,
+            ctx.mint_manager.key,
+            &[],
+            1,
+            mint.decimals,
+        )?,
+        &[
+            ctx.renter_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.owner_token_account.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // freeze accounts, close thaws the emptied renter account again
+    for token_account_info in [ctx.renter_token_account, ctx.owner_token_account] {
+        invoke_signed(
+            &spl_token_2022::instruction::freeze_account(
+                ctx.token_program.key,
+                token_account_info.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
+    // only the rental lock is released, locks held by other programs stay in place
+    remove_in_use_lock(ctx.mint_manager, None, ctx.rental.key)?;
+
+    let destination_starting_lamports = ctx.owner.lamports();
+    **ctx.owner.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(ctx.rental.lamports())
+        .expect("Add error");
+    **ctx.rental.lamports.borrow_mut() = 0;
+
+    ctx.rental.assign(&system_program::id());
+    ctx.rental
+        .realloc(0, false)
+        .expect("Error reallocating account");
+
+    Ok(())
+}
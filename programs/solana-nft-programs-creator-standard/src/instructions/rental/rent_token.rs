@@ -0,0 +1,339 @@
+use crate::errors::ErrorCode;
+use crate::state::additional_in_use_locks;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::assert_mint_manager_seeds;
+use crate::state::assert_rental_seeds;
+use crate::state::assert_transfer_instructions;
+use crate::state::is_in_use_expired;
+use crate::state::next_collection_manager;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUsePurpose;
+use crate::state::MintManager;
+use crate::state::Rental;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::utils::assert_with_msg;
+use crate::utils::unpack_checked_mint_account;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction::transfer;
+use solana_program::system_program;
+use solana_program::sysvar;
+use solana_program::sysvar::Sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn rent_token(
+    program_id: Pubkey,
+    rental: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    ruleset: Pubkey,
+    owner_token_account: Pubkey,
+    renter_token_account: Pubkey,
+    owner: Pubkey,
+    renter: Pubkey,
+    token_program: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(rental, false),
+            AccountMeta::new(mint_manager, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(ruleset, false),
+            AccountMeta::new(owner_token_account, false),
+            AccountMeta::new(renter_token_account, false),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(renter, true),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        ],
+        data: CreatorStandardInstruction::RentToken.try_to_vec()?,
+    })
+}
+
+pub struct RentTokenCtx<'a, 'info> {
+    pub rental: &'a AccountInfo<'info>,
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub owner_token_account: &'a AccountInfo<'info>,
+    pub renter_token_account: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub renter: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub instructions: &'a AccountInfo<'info>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> RentTokenCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            rental: next_account_info(account_iter)?,
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            owner_token_account: next_account_info(account_iter)?,
+            renter_token_account: next_account_info(account_iter)?,
+            owner: next_account_info(account_iter)?,
+            renter: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            instructions: next_account_info(account_iter)?,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let rental: Rental = Rental::from_account_info(ctx.rental)?;
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let renter_token_account =
+            unpack_checked_token_account(ctx.renter_token_account, Some("renter_token_account"))?;
+        if rental.renter.is_some() {
+            return Err(ProgramError::from(ErrorCode::RentalAlreadyRented));
+        }
+
+        // rental
+        assert_mut(ctx.rental, "rental")?;
+        assert_rental_seeds(ctx.mint.key, ctx.rental.key)?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+        assert_address(
+            &mint_manager.in_use_by.unwrap_or_default(),
+            ctx.rental.key,
+            "mint_manager in_use_by",
+        )?;
+
+        ///// no checks for mint /////
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // owner_token_account
+        assert_mut(ctx.owner_token_account, "owner_token_account")?;
+        assert_address(
+            ctx.owner_token_account.key,
+            &rental.owner_token_account,
+            "owner_token_account",
+        )?;
+
+        // renter_token_account
+        assert_mut(ctx.renter_token_account, "renter_token_account")?;
+        assert_address(
+            &renter_token_account.mint,
+            ctx.mint.key,
+            "renter_token_account mint",
+        )?;
+        assert_address(
+            &renter_token_account.owner,
+            ctx.renter.key,
+            "renter_token_account owner",
+        )?;
+
+        // owner
+        assert_mut(ctx.owner, "owner")?;
+        assert_address(ctx.owner.key, &rental.owner, "owner")?;
+
+        // renter
+        assert_signer(ctx.renter, "renter")?;
+        assert_mut(ctx.renter, "renter")?;
+        assert_with_msg(
+            ctx.renter.key != &rental.owner,
+            ProgramError::InvalidInstructionData,
+            "renter cannot be the owner",
+        )?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        // instructions
+        assert_address(
+            ctx.instructions.key,
+            &sysvar::instructions::id(),
+            "instructions",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: RentTokenCtx) -> ProgramResult {
+    let mut rental: Rental = Rental::from_account_info(ctx.rental)?;
+    let mut mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
+    // rentals move the token without a sale so they are held to the same rules as a plain transfer
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    if ruleset.require_royalty_payment {
+        return Err(ProgramError::from(ErrorCode::RoyaltyPaymentRequired));
+    }
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    assert_transfer_instructions(&ruleset, ctx.instructions, remaining_accounts)?;
+    // only the rental itself may hold the token in use
+    for lock in additional_in_use_locks(&mint_manager, None)? {
+        if !is_in_use_expired(lock.in_use_until)? {
+            return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
+        }
+    }
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    let renter_token_account =
+        unpack_checked_token_account(ctx.renter_token_account, Some("renter_token_account"))?;
+    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+
+    // pay owner
+    if rental.price > 0 {
+        invoke(
+            &transfer(ctx.renter.key, ctx.owner.key, rental.price),
+            &[ctx.renter.clone(), ctx.owner.clone()],
+        )?;
+    }
+
+    // thaw accounts
+    invoke_signed(
+        &spl_token_2022::instruction::thaw_account(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            &[],
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+    if renter_token_account.is_frozen() {
+        invoke_signed(
+            &spl_token_2022::instruction::thaw_account(
+                ctx.token_program.key,
+                ctx.renter_token_account.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                ctx.renter_token_account.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
+    // transfer to renter as delegate
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            ctx.token_program.key,
+            ctx.owner_token_account.key,
+            ctx.mint.key,
+            ctx.renter_token_account.key,
+            ctx.mint_manager.key,
+            &[],
+            1,
+            mint.decimals,
+        )?,
+        &[
+            ctx.owner_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.renter_token_account.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // approve mint manager so the token can be reclaimed after expiration
+    invoke(
+        &spl_token_2022::instruction::approve_checked(
+            ctx.token_program.key,
+            ctx.renter_token_account.key,
+            ctx.mint.key,
+            ctx.mint_manager.key,
+            ctx.renter.key,
+            &[],
+            1,
+            mint.decimals,
+        )?,
+        &[
+            ctx.renter_token_account.clone(),
+            ctx.mint.clone(),
+            ctx.mint_manager.clone(),
+            ctx.renter.clone(),
+        ],
+    )?;
+
+    // freeze accounts
+    for token_account_info in [ctx.owner_token_account, ctx.renter_token_account] {
+        invoke_signed(
+            &spl_token_2022::instruction::freeze_account(
+                ctx.token_program.key,
+                token_account_info.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
+    rental.renter = Some(*ctx.renter.key);
+    rental.renter_token_account = Some(*ctx.renter_token_account.key);
+    rental.expiration = Some(
+        Clock::get()?
+            .unix_timestamp
+            .checked_add(rental.duration_seconds)
+            .ok_or(ErrorCode::InvalidRental)?,
+    );
+    rental.save(ctx.rental)?;
+
+    mint_manager.in_use_purpose = InUsePurpose::Rented;
+    mint_manager.save(ctx.mint_manager)?;
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::errors::ErrorCode;
 use crate::state::CreatorStandardAccount;
 use crate::state::Ruleset;
 use crate::utils::assert_address;
@@ -44,6 +45,9 @@ impl<'a, 'info> CloseRulesetCtx<'a, 'info> {
         };
         // deserializations
         let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+        if ruleset.locked {
+            return Err(ProgramError::from(ErrorCode::RulesetLocked));
+        }
 
         // ruleset
         assert_mut(ctx.ruleset, "ruleset")?;
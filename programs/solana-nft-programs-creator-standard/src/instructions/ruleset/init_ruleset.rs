@@ -4,7 +4,10 @@ use crate::state::calculate_ruleset_size;
 use crate::CreatorStandardInstruction;
 
 use crate::state::CreatorStandardAccount;
+use crate::state::RequiredInstruction;
 use crate::state::Ruleset;
+use crate::state::RulesetCategory;
+use crate::state::RULESET_VERSION;
 use crate::utils::assert_address;
 use crate::utils::assert_empty;
 use crate::utils::assert_mut;
@@ -34,6 +37,21 @@ pub fn init_ruleset(
     allowed_programs: Vec<Pubkey>,
     disallowed_addresses: Vec<Pubkey>,
     extensions: Vec<Pubkey>,
+    require_attachment_approval: bool,
+    approved_creators: Vec<Pubkey>,
+    uri: String,
+    description_hash: [u8; 32],
+    category: RulesetCategory,
+    minimum_creator_basis_points: u16,
+    required_creators: Vec<Pubkey>,
+    require_royalty_payment: bool,
+    payment_mints: Vec<Pubkey>,
+    pre_transfer_instructions: Vec<RequiredInstruction>,
+    post_transfer_instructions: Vec<RequiredInstruction>,
+    allow_recall: bool,
+    disallow_suspension: bool,
+    in_use_release_grace_period_seconds: Option<i64>,
+    allow_authority_remove_in_use_by: bool,
 ) -> Result<Instruction, ProgramError> {
     Ok(Instruction {
         program_id,
@@ -48,6 +66,21 @@ pub fn init_ruleset(
             disallowed_addresses,
             allowed_programs,
             extensions,
+            require_attachment_approval,
+            approved_creators,
+            uri,
+            description_hash,
+            category,
+            minimum_creator_basis_points,
+            required_creators,
+            require_royalty_payment,
+            payment_mints,
+            pre_transfer_instructions,
+            post_transfer_instructions,
+            allow_recall,
+            disallow_suspension,
+            in_use_release_grace_period_seconds,
+            allow_authority_remove_in_use_by,
         })
         .try_to_vec()?,
     })
@@ -61,6 +94,21 @@ pub struct InitRulesetIx {
     pub allowed_programs: Vec<Pubkey>,
     pub disallowed_addresses: Vec<Pubkey>,
     pub extensions: Vec<Pubkey>,
+    pub require_attachment_approval: bool,
+    pub approved_creators: Vec<Pubkey>,
+    pub uri: String,
+    pub description_hash: [u8; 32],
+    pub category: RulesetCategory,
+    pub minimum_creator_basis_points: u16,
+    pub required_creators: Vec<Pubkey>,
+    pub require_royalty_payment: bool,
+    pub payment_mints: Vec<Pubkey>,
+    pub pre_transfer_instructions: Vec<RequiredInstruction>,
+    pub post_transfer_instructions: Vec<RequiredInstruction>,
+    pub allow_recall: bool,
+    pub disallow_suspension: bool,
+    pub in_use_release_grace_period_seconds: Option<i64>,
+    pub allow_authority_remove_in_use_by: bool,
 }
 
 pub struct InitRulesetCtx<'a, 'info> {
@@ -104,9 +152,16 @@ impl<'a, 'info> InitRulesetCtx<'a, 'info> {
 pub fn handler(ctx: InitRulesetCtx, ix: InitRulesetIx) -> ProgramResult {
     let ruleset_seeds = assert_ruleset_seeds(&ix.name, ctx.ruleset.key)?;
     let ruleset_space = calculate_ruleset_size(
+        &ix.name,
         &ix.allowed_programs,
         &ix.disallowed_addresses,
         &ix.extensions,
+        &ix.approved_creators,
+        &ix.uri,
+        &ix.required_creators,
+        &ix.payment_mints,
+        &ix.pre_transfer_instructions,
+        &ix.post_transfer_instructions,
     );
     invoke_signed(
         &create_account(
@@ -124,12 +179,27 @@ pub fn handler(ctx: InitRulesetCtx, ix: InitRulesetIx) -> ProgramResult {
     )?;
 
     let mut ruleset: Ruleset = Ruleset::new();
-    ruleset.version = 0;
+    ruleset.version = RULESET_VERSION;
     ruleset.authority = *ctx.authority.key;
     ruleset.name = ix.name;
     ruleset.allowed_programs = ix.allowed_programs;
     ruleset.disallowed_addresses = ix.disallowed_addresses;
     ruleset.extensions = ix.extensions;
+    ruleset.require_attachment_approval = ix.require_attachment_approval;
+    ruleset.approved_creators = ix.approved_creators;
+    ruleset.uri = ix.uri;
+    ruleset.description_hash = ix.description_hash;
+    ruleset.category = ix.category;
+    ruleset.minimum_creator_basis_points = ix.minimum_creator_basis_points;
+    ruleset.required_creators = ix.required_creators;
+    ruleset.require_royalty_payment = ix.require_royalty_payment;
+    ruleset.payment_mints = ix.payment_mints;
+    ruleset.pre_transfer_instructions = ix.pre_transfer_instructions;
+    ruleset.post_transfer_instructions = ix.post_transfer_instructions;
+    ruleset.allow_recall = ix.allow_recall;
+    ruleset.disallow_suspension = ix.disallow_suspension;
+    ruleset.in_use_release_grace_period_seconds = ix.in_use_release_grace_period_seconds;
+    ruleset.allow_authority_remove_in_use_by = ix.allow_authority_remove_in_use_by;
     ruleset.save(ctx.ruleset)?;
 
     Ok(())
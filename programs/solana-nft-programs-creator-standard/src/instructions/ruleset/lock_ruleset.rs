@@ -0,0 +1,74 @@
+use crate::errors::ErrorCode;
+use crate::state::CreatorStandardAccount;
+use crate::state::Ruleset;
+use crate::state::RULESET_VERSION;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_signer;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn lock_ruleset(
+    program_id: Pubkey,
+    ruleset: Pubkey,
+    authority: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(ruleset, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: CreatorStandardInstruction::LockRuleset.try_to_vec()?,
+    })
+}
+
+pub struct LockRulesetCtx<'a, 'info> {
+    pub ruleset: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> LockRulesetCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            ruleset: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+        };
+        // deserializations
+        let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+        // older rulesets do not store the lock
+        if ruleset.version < RULESET_VERSION {
+            return Err(ProgramError::from(ErrorCode::MigrationRequired));
+        }
+        if ruleset.locked {
+            return Err(ProgramError::from(ErrorCode::RulesetLocked));
+        }
+
+        // ruleset
+        assert_mut(ctx.ruleset, "ruleset")?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        assert_address(ctx.authority.key, &ruleset.authority, "authority")?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: LockRulesetCtx) -> ProgramResult {
+    let mut ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    // once locked the ruleset can never be updated or closed again
+    ruleset.locked = true;
+    ruleset.save(ctx.ruleset)?;
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::state::calculate_ruleset_size;
+use crate::state::CreatorStandardAccount;
+use crate::state::Ruleset;
+use crate::state::RULESET_VERSION;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::grow_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_ruleset(
+    program_id: Pubkey,
+    ruleset: Pubkey,
+    payer: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(ruleset, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: CreatorStandardInstruction::MigrateRuleset.try_to_vec()?,
+    })
+}
+
+pub struct MigrateRulesetCtx<'a, 'info> {
+    pub ruleset: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MigrateRulesetCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            ruleset: next_account_info(account_iter)?,
+            payer: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+        };
+
+        // ruleset
+        assert_mut(ctx.ruleset, "ruleset")?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // payer
+        assert_signer(ctx.payer, "payer")?;
+        assert_mut(ctx.payer, "payer")?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: MigrateRulesetCtx) -> ProgramResult {
+    // older rulesets decode with defaults for the fields added since, so only space and version change
+    let mut ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    ruleset.version = RULESET_VERSION;
+    let ruleset_space = calculate_ruleset_size(
+        &ruleset.name,
+        &ruleset.allowed_programs,
+        &ruleset.disallowed_addresses,
+        &ruleset.extensions,
+        &ruleset.approved_creators,
+        &ruleset.uri,
+        &ruleset.required_creators,
+        &ruleset.payment_mints,
+        &ruleset.pre_transfer_instructions,
+        &ruleset.post_transfer_instructions,
+    );
+    grow_account(ctx.ruleset, ruleset_space, ctx.payer, ctx.system_program)?;
+    ruleset.save(ctx.ruleset)?;
+
+    Ok(())
+}
@@ -1,7 +1,13 @@
+pub mod clone_ruleset;
 pub mod close_ruleset;
 pub mod init_ruleset;
+pub mod lock_ruleset;
+pub mod migrate_ruleset;
 pub mod update_ruleset;
 
+pub use clone_ruleset::*;
 pub use close_ruleset::*;
 pub use init_ruleset::*;
+pub use lock_ruleset::*;
+pub use migrate_ruleset::*;
 pub use update_ruleset::*;
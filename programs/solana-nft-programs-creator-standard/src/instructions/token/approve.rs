@@ -1,16 +1,22 @@
 use crate::errors::ErrorCode;
 use crate::state::allowlist_disallowlist;
+use crate::state::assert_holder_token_amount;
+use crate::state::assert_mint_manager_ruleset;
 use crate::state::assert_mint_manager_seeds;
 use crate::state::is_base_program;
+use crate::state::is_token_in_use;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
 use crate::state::Ruleset;
 use crate::utils::assert_address;
-use crate::utils::assert_amount;
 use crate::utils::assert_mut;
 use crate::utils::assert_program_account;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::assert_with_msg;
+use crate::utils::unpack_checked_mint_account;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
 use borsh::BorshDeserialize;
@@ -34,6 +40,7 @@ pub fn approve(
     holder_token_account: Pubkey,
     holder: Pubkey,
     delegate: Pubkey,
+    token_program: Pubkey,
     amount: u64,
 ) -> Result<Instruction, ProgramError> {
     Ok(Instruction {
@@ -45,7 +52,7 @@ pub fn approve(
             AccountMeta::new(holder_token_account, false),
             AccountMeta::new_readonly(holder, true),
             AccountMeta::new_readonly(delegate, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program, false),
         ],
         data: CreatorStandardInstruction::Approve(ApproveIx { amount }).try_to_vec()?,
     })
@@ -65,13 +72,14 @@ pub struct ApproveCtx<'a, 'info> {
     pub holder: &'a AccountInfo<'info>,
     pub delegate: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
     pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> ApproveCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             ruleset: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
@@ -79,6 +87,7 @@ impl<'a, 'info> ApproveCtx<'a, 'info> {
             holder: next_account_info(account_iter)?,
             delegate: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
+            in_use_record: None,
             remaining_accounts: account_iter.collect(),
         };
         // deserializations
@@ -90,16 +99,25 @@ impl<'a, 'info> ApproveCtx<'a, 'info> {
         assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
 
         // ruleset
-        assert_address(&mint_manager.ruleset, ctx.ruleset.key, "ruleset")?;
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
         assert_program_account(ctx.ruleset, Ruleset::hash())?;
 
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+
         ///// no checks for mint /////
 
         // holder_token_account
         assert_mut(ctx.holder_token_account, "holder_token_account")?;
-        assert_amount(
-            &holder_token_account.amount.to_string(),
-            "1",
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
             "holder_token_account",
         )?;
         assert_address(
@@ -124,7 +142,7 @@ impl<'a, 'info> ApproveCtx<'a, 'info> {
         ///// no checks for delegate /////
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         Ok(ctx)
     }
@@ -133,9 +151,13 @@ impl<'a, 'info> ApproveCtx<'a, 'info> {
 pub fn handler(ctx: ApproveCtx, ix: ApproveIx) -> ProgramResult {
     let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
     let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
-    if mint_manager.in_use_by.is_some() {
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
+    if is_token_in_use(&mint_manager, ctx.in_use_record)? {
         return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
     }
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
     let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
     let remaining_accounts = &mut ctx.remaining_accounts.iter();
 
@@ -160,7 +182,7 @@ pub fn handler(ctx: ApproveCtx, ix: ApproveIx) -> ProgramResult {
 
     // thaw account
     invoke_signed(
-        &spl_token::instruction::thaw_account(
+        &spl_token_2022::instruction::thaw_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -180,7 +202,7 @@ pub fn handler(ctx: ApproveCtx, ix: ApproveIx) -> ProgramResult {
 
     // appprove
     invoke_signed(
-        &spl_token::instruction::approve_checked(
+        &spl_token_2022::instruction::approve_checked(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -188,7 +210,7 @@ pub fn handler(ctx: ApproveCtx, ix: ApproveIx) -> ProgramResult {
             ctx.holder.key,
             &[],
             ix.amount,
-            0,
+            mint.decimals,
         )?,
         &[
             ctx.holder_token_account.clone(),
@@ -205,7 +227,7 @@ pub fn handler(ctx: ApproveCtx, ix: ApproveIx) -> ProgramResult {
 
     // freeze account
     invoke_signed(
-        &spl_token::instruction::freeze_account(
+        &spl_token_2022::instruction::freeze_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
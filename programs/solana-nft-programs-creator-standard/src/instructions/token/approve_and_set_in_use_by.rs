@@ -1,16 +1,24 @@
 use crate::errors::ErrorCode;
-use crate::state::allowlist_disallowlist;
+use crate::state::assert_holder_token_amount;
+use crate::state::assert_in_use_by_allowed;
+use crate::state::assert_in_use_until;
+use crate::state::assert_mint_manager_ruleset;
 use crate::state::assert_mint_manager_seeds;
-use crate::state::is_base_program;
+use crate::state::is_token_in_use;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::save_in_use_by;
 use crate::state::CreatorStandardAccount;
+use crate::state::InUsePurpose;
 use crate::state::MintManager;
 use crate::state::Ruleset;
 use crate::utils::assert_address;
-use crate::utils::assert_amount;
 use crate::utils::assert_mut;
 use crate::utils::assert_program_account;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::assert_with_msg;
+use crate::utils::unpack_checked_mint_account;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
 use borsh::BorshDeserialize;
@@ -34,8 +42,12 @@ pub fn approve_and_set_in_use_by(
     holder_token_account: Pubkey,
     holder: Pubkey,
     delegate: Pubkey,
+    token_program: Pubkey,
     amount: u64,
     in_use_by_address: Pubkey,
+    in_use_until: Option<i64>,
+    purpose: InUsePurpose,
+    reference: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
     Ok(Instruction {
         program_id,
@@ -47,10 +59,15 @@ pub fn approve_and_set_in_use_by(
             AccountMeta::new(holder_token_account, false),
             AccountMeta::new_readonly(holder, true),
             AccountMeta::new_readonly(delegate, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program, false),
         ],
-        data: CreatorStandardInstruction::ApproveAndSetInUseBy(ApproveAndSetInUseByIx { amount })
-            .try_to_vec()?,
+        data: CreatorStandardInstruction::ApproveAndSetInUseBy(ApproveAndSetInUseByIx {
+            amount,
+            in_use_until,
+            purpose,
+            reference,
+        })
+        .try_to_vec()?,
     })
 }
 
@@ -59,6 +76,9 @@ pub fn approve_and_set_in_use_by(
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct ApproveAndSetInUseByIx {
     pub amount: u64,
+    pub in_use_until: Option<i64>,
+    pub purpose: InUsePurpose,
+    pub reference: Option<Pubkey>,
 }
 pub struct ApproveAndSetInUseByCtx<'a, 'info> {
     pub mint_manager: &'a AccountInfo<'info>,
@@ -69,13 +89,14 @@ pub struct ApproveAndSetInUseByCtx<'a, 'info> {
     pub holder: &'a AccountInfo<'info>,
     pub delegate: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
     pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> ApproveAndSetInUseByCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             ruleset: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
@@ -84,6 +105,7 @@ impl<'a, 'info> ApproveAndSetInUseByCtx<'a, 'info> {
             holder: next_account_info(account_iter)?,
             delegate: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
+            in_use_record: None,
             remaining_accounts: account_iter.collect(),
         };
 
@@ -97,18 +119,30 @@ impl<'a, 'info> ApproveAndSetInUseByCtx<'a, 'info> {
         assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
 
         // ruleset
-        assert_address(&mint_manager.ruleset, ctx.ruleset.key, "ruleset")?;
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
         assert_program_account(ctx.ruleset, Ruleset::hash())?;
 
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
         ///// no checks for mint /////
 
         ///// no checks for in_use_by_address /////
 
         // holder_token_account
         assert_mut(ctx.holder_token_account, "holder_token_account")?;
-        assert_amount(
-            &holder_token_account.amount.to_string(),
-            "1",
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
             "holder_token_account",
         )?;
         assert_address(
@@ -133,7 +167,7 @@ impl<'a, 'info> ApproveAndSetInUseByCtx<'a, 'info> {
         ///// no checks for delegate /////
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         Ok(ctx)
     }
@@ -141,37 +175,26 @@ impl<'a, 'info> ApproveAndSetInUseByCtx<'a, 'info> {
 
 pub fn handler(ctx: ApproveAndSetInUseByCtx, ix: ApproveAndSetInUseByIx) -> ProgramResult {
     let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
-    let mint_manager: &mut MintManager = &mut MintManager::from_account_info(ctx.mint_manager)?;
-    if mint_manager.in_use_by.is_some() {
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
+    if is_token_in_use(&mint_manager, ctx.in_use_record)? {
         return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
     }
+    assert_in_use_until(ix.in_use_until)?;
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
     let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
 
-    // set_in_use_by
-    mint_manager.in_use_by = Some(*ctx.in_use_by_address.key);
-
-    let remaining_accounts = &mut ctx.remaining_accounts.iter();
-    /////////////// check allowed / disallowed ///////////////
-    let [allowed_programs, disallowed_addresses] =
-        allowlist_disallowlist(&ruleset, remaining_accounts)?;
-    if !allowed_programs.is_empty()
-        && !is_base_program(ctx.in_use_by_address.owner)
-        && !allowed_programs.contains(&ctx.in_use_by_address.owner.to_string())
-    {
-        return Err(ProgramError::from(ErrorCode::ProgramNotAllowed));
-    }
-
-    if !disallowed_addresses.is_empty()
-        && (disallowed_addresses.contains(&ctx.in_use_by_address.owner.to_string())
-            || disallowed_addresses.contains(&ctx.in_use_by_address.key.to_string()))
-    {
-        return Err(ProgramError::from(ErrorCode::AddressDisallowed));
-    }
-    ////////////////////////////////////////////////////////////
+    assert_in_use_by_allowed(
+        &ruleset,
+        ctx.in_use_by_address,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
 
     // thaw account
     invoke_signed(
-        &spl_token::instruction::thaw_account(
+        &spl_token_2022::instruction::thaw_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -191,7 +214,7 @@ pub fn handler(ctx: ApproveAndSetInUseByCtx, ix: ApproveAndSetInUseByIx) -> Prog
 
     // appprove
     invoke_signed(
-        &spl_token::instruction::approve_checked(
+        &spl_token_2022::instruction::approve_checked(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -199,7 +222,7 @@ pub fn handler(ctx: ApproveAndSetInUseByCtx, ix: ApproveAndSetInUseByIx) -> Prog
             ctx.holder.key,
             &[],
             ix.amount,
-            0,
+            mint.decimals,
         )?,
         &[
             ctx.holder_token_account.clone(),
@@ -216,7 +239,7 @@ pub fn handler(ctx: ApproveAndSetInUseByCtx, ix: ApproveAndSetInUseByIx) -> Prog
 
     // freeze account
     invoke_signed(
-        &spl_token::instruction::freeze_account(
+        &spl_token_2022::instruction::freeze_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -234,7 +257,15 @@ pub fn handler(ctx: ApproveAndSetInUseByCtx, ix: ApproveAndSetInUseByIx) -> Prog
             .collect::<Vec<&[u8]>>()],
     )?;
 
-    mint_manager.save(ctx.mint_manager)?;
+    // set_in_use_by
+    save_in_use_by(
+        ctx.mint_manager,
+        ctx.in_use_record,
+        Some(*ctx.in_use_by_address.key),
+        ix.in_use_until,
+        ix.purpose,
+        ix.reference,
+    )?;
 
     Ok(())
 }
@@ -1,14 +1,20 @@
 use crate::errors::ErrorCode;
+use crate::state::assert_holder_token_amount;
 use crate::state::assert_mint_manager_seeds;
+use crate::state::close_in_use_record;
+use crate::state::is_token_in_use;
+use crate::state::next_in_use_record;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
+use crate::state::MintManagerKind;
 use crate::utils::assert_address;
-use crate::utils::assert_amount;
 use crate::utils::assert_mut;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::unpack_checked_mint_account;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
 use solana_program::account_info::AccountInfo;
@@ -28,21 +34,35 @@ pub fn burn(
     holder_token_account: Pubkey,
     holder: Pubkey,
     delegate: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
+    amount: u64,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(holder_token_account, false),
+        AccountMeta::new(holder, true),
+        AccountMeta::new_readonly(delegate, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new(mint_manager, false),
-            AccountMeta::new(mint, false),
-            AccountMeta::new(holder_token_account, false),
-            AccountMeta::new(holder, true),
-            AccountMeta::new_readonly(delegate, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data: CreatorStandardInstruction::Burn.try_to_vec()?,
+        accounts,
+        data: CreatorStandardInstruction::Burn(BurnIx { amount }).try_to_vec()?,
     })
 }
 
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct BurnIx {
+    pub amount: u64,
+}
+
 pub struct BurnCtx<'a, 'info> {
     pub mint_manager: &'a AccountInfo<'info>,
     pub mint: &'a AccountInfo<'info>,
@@ -50,18 +70,22 @@ pub struct BurnCtx<'a, 'info> {
     pub holder: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
     pub system_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> BurnCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
             holder_token_account: next_account_info(account_iter)?,
             holder: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
             system_program: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
         };
         // deserializations
         let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
@@ -77,9 +101,9 @@ impl<'a, 'info> BurnCtx<'a, 'info> {
 
         // holder_token_account
         assert_mut(ctx.holder_token_account, "holder_token_account")?;
-        assert_amount(
-            &holder_token_account.amount.to_string(),
-            "1",
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
             "holder_token_account",
         )?;
         assert_address(
@@ -93,12 +117,22 @@ impl<'a, 'info> BurnCtx<'a, 'info> {
             "holder_token_account owner",
         )?;
 
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
         // holder
         assert_mut(ctx.holder, "holder")?;
         assert_signer(ctx.holder, "holder")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         // system_program
         assert_address(
@@ -111,23 +145,28 @@ impl<'a, 'info> BurnCtx<'a, 'info> {
     }
 }
 
-pub fn handler(ctx: BurnCtx) -> ProgramResult {
+pub fn handler(ctx: BurnCtx, ix: BurnIx) -> ProgramResult {
     let mint = unpack_checked_mint_account(ctx.mint, Some("token mint"))?;
     let holder_token_account =
         unpack_checked_token_account(ctx.holder_token_account, Some("holder token account"))?;
     let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
-    if mint_manager.in_use_by.is_some() {
+    if is_token_in_use(&mint_manager, ctx.in_use_record)? {
         return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
     }
     let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
 
-    if mint.supply > 1 || mint.supply != holder_token_account.amount {
+    if mint_manager.kind == MintManagerKind::NonFungible
+        && (mint.supply > 1 || mint.supply != holder_token_account.amount)
+    {
         return Err(ProgramError::from(ErrorCode::InvalidMint));
     }
+    if ix.amount == 0 || ix.amount > holder_token_account.amount {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
 
     // thaw account
     invoke_signed(
-        &spl_token::instruction::thaw_account(
+        &spl_token_2022::instruction::thaw_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -147,14 +186,14 @@ pub fn handler(ctx: BurnCtx) -> ProgramResult {
 
     // burn
     invoke_signed(
-        &spl_token::instruction::burn_checked(
+        &spl_token_2022::instruction::burn_checked(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
             ctx.holder.key,
             &[],
-            1,
-            0,
+            ix.amount,
+            mint.decimals,
         )?,
         &[
             ctx.holder_token_account.clone(),
@@ -168,9 +207,32 @@ pub fn handler(ctx: BurnCtx) -> ProgramResult {
             .collect::<Vec<&[u8]>>()],
     )?;
 
+    if ix.amount < holder_token_account.amount {
+        // freeze remaining balance
+        invoke_signed(
+            &spl_token_2022::instruction::freeze_account(
+                ctx.token_program.key,
+                ctx.holder_token_account.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                ctx.holder_token_account.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+        return Ok(());
+    }
+
     // close account
     invoke_signed(
-        &spl_token::instruction::close_account(
+        &spl_token_2022::instruction::close_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.holder.key,
@@ -184,6 +246,7 @@ pub fn handler(ctx: BurnCtx) -> ProgramResult {
         ],
         &[],
     )?;
+    close_in_use_record(ctx.in_use_record, ctx.holder)?;
 
     Ok(())
 }
@@ -1,9 +1,14 @@
-use crate::CreatorStandardInstruction;
 use crate::state::assert_mint_manager_seeds;
+use crate::state::close_in_use_record;
+use crate::state::next_in_use_record;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
 use crate::utils::assert_address;
 use crate::utils::assert_mut;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
 use solana_program::account_info::AccountInfo;
@@ -21,16 +26,22 @@ pub fn close(
     mint: Pubkey,
     token_account: Pubkey,
     owner: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(mint_manager, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(token_account, false),
+        AccountMeta::new_readonly(owner, true),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(mint_manager, false),
-            AccountMeta::new(mint, false),
-            AccountMeta::new(token_account, false),
-            AccountMeta::new_readonly(owner, true),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
+        accounts,
         data: CreatorStandardInstruction::Close.try_to_vec()?,
     })
 }
@@ -41,22 +52,28 @@ pub struct CloseCtx<'a, 'info> {
     pub token_account: &'a AccountInfo<'info>,
     pub owner: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> CloseCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
             token_account: next_account_info(account_iter)?,
             owner: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
         };
         // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
         let token_account = unpack_checked_token_account(ctx.token_account, Some("token_account"))?;
 
-        ///// no checks for mint_manager /////
+        // mint_manager
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
 
         // mint
         assert_mut(ctx.mint, "mint")?;
@@ -69,7 +86,17 @@ impl<'a, 'info> CloseCtx<'a, 'info> {
         assert_signer(ctx.owner, "owner")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        // in_use_record, closed with the token account so a recreated account starts without locks
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
 
         Ok(ctx)
     }
@@ -82,7 +109,7 @@ pub fn handler(ctx: CloseCtx) -> ProgramResult {
 
         // thaw account
         invoke_signed(
-            &spl_token::instruction::thaw_account(
+            &spl_token_2022::instruction::thaw_account(
                 ctx.token_program.key,
                 ctx.token_account.key,
                 ctx.mint.key,
@@ -103,7 +130,7 @@ pub fn handler(ctx: CloseCtx) -> ProgramResult {
 
     // close account
     invoke_signed(
-        &spl_token::instruction::close_account(
+        &spl_token_2022::instruction::close_account(
             ctx.token_program.key,
             ctx.token_account.key,
             ctx.owner.key,
@@ -117,6 +144,7 @@ pub fn handler(ctx: CloseCtx) -> ProgramResult {
         ],
         &[],
     )?;
+    close_in_use_record(ctx.in_use_record, ctx.owner)?;
 
     Ok(())
 }
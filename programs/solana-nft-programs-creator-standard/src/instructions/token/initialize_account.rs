@@ -1,6 +1,7 @@
 use crate::utils::assert_address;
 use crate::utils::assert_mut;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::CreatorStandardInstruction;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
@@ -13,7 +14,7 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 use solana_program::sysvar;
-use spl_associated_token_account::create_associated_token_account;
+use spl_associated_token_account::instruction::create_associated_token_account;
 
 #[allow(clippy::too_many_arguments)]
 pub fn initialize_account(
@@ -23,6 +24,7 @@ pub fn initialize_account(
     owner: Pubkey,
     payer: Pubkey,
     rent: Pubkey,
+    token_program: Pubkey,
 ) -> Result<Instruction, ProgramError> {
     Ok(Instruction {
         program_id,
@@ -32,7 +34,7 @@ pub fn initialize_account(
             AccountMeta::new_readonly(owner, false),
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(rent, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::id(), false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
         ],
@@ -80,11 +82,11 @@ impl<'a, 'info> InitializeAccountCtx<'a, 'info> {
         assert_address(ctx.rent.key, &sysvar::rent::id(), "rent")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         // associated_token_program
         assert_address(
-            ctx.token_program.key,
+            ctx.associated_token_program.key,
             &spl_associated_token_account::id(),
             "associated_token_program",
         )?;
@@ -102,13 +104,19 @@ impl<'a, 'info> InitializeAccountCtx<'a, 'info> {
 
 pub fn handler(ctx: InitializeAccountCtx) -> ProgramResult {
     invoke_signed(
-        &create_associated_token_account(ctx.payer.key, ctx.token_account.key, ctx.mint.key),
+        &create_associated_token_account(
+            ctx.payer.key,
+            ctx.owner.key,
+            ctx.mint.key,
+            ctx.token_program.key,
+        ),
         &[
             ctx.payer.clone(),
             ctx.token_account.clone(),
             ctx.owner.clone(),
             ctx.mint.clone(),
             ctx.system_program.clone(),
+            ctx.token_program.clone(),
             ctx.rent.clone(),
         ],
         &[],
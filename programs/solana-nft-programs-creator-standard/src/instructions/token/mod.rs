@@ -3,13 +3,21 @@ pub mod approve_and_set_in_use_by;
 pub mod burn;
 pub mod close;
 pub mod initialize_account;
+pub mod recall;
 pub mod revoke;
 pub mod transfer;
+pub mod transfer_by_in_user;
+pub mod transfer_with_payment;
+pub mod transfer_with_token_payment;
 
 pub use approve::*;
 pub use approve_and_set_in_use_by::*;
 pub use burn::*;
 pub use close::*;
 pub use initialize_account::*;
+pub use recall::*;
 pub use revoke::*;
 pub use transfer::*;
+pub use transfer_by_in_user::*;
+pub use transfer_with_payment::*;
+pub use transfer_with_token_payment::*;
@@ -0,0 +1,257 @@
+use crate::errors::ErrorCode;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::assert_mint_manager_seeds;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::rental_seeds;
+use crate::state::save_additional_in_use_locks;
+use crate::state::save_in_use_by;
+use crate::state::CreatorStandardAccount;
+use crate::state::InUsePurpose;
+use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::utils::unpack_checked_mint_account;
+use crate::utils::unpack_checked_token_account;
+use crate::utils::unpack_mint_permanent_delegate;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[allow(clippy::too_many_arguments)]
+pub fn recall(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    ruleset: Pubkey,
+    from: Pubkey,
+    to: Pubkey,
+    authority: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new(to, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::Recall(RecallIx { amount }).try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RecallIx {
+    pub amount: u64,
+}
+
+pub struct RecallCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub from: &'a AccountInfo<'info>,
+    pub to: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> RecallCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            from: next_account_info(account_iter)?,
+            to: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let from_account = unpack_checked_token_account(ctx.from, Some("from"))?;
+        let to_account = unpack_checked_token_account(ctx.to, Some("to"))?;
+        if mint_manager.recall_disabled {
+            return Err(ProgramError::from(ErrorCode::RecallDisabled));
+        }
+        // the rental escrow holds the owner and renter token accounts until it is cancelled or reclaimed
+        if mint_manager.in_use_by == Some(rental_seeds(ctx.mint.key).0) {
+            return Err(ProgramError::from(ErrorCode::RecallDuringRental));
+        }
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+
+        // mint
+        if unpack_mint_permanent_delegate(ctx.mint)? != Some(*ctx.mint_manager.key) {
+            return Err(ProgramError::from(ErrorCode::RecallNotSupported));
+        }
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+        let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+        if !ruleset.allow_recall {
+            return Err(ProgramError::from(ErrorCode::RecallNotAllowed));
+        }
+        // recall moves the token without a sale so it cannot pay royalties
+        if ruleset.require_royalty_payment {
+            return Err(ProgramError::from(ErrorCode::RoyaltyPaymentRequired));
+        }
+
+        // in_use_record
+        ctx.in_use_record =
+            next_in_use_record(&mint_manager, ctx.from.key, &mut ctx.remaining_accounts)?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
+        // from
+        assert_mut(ctx.from, "from")?;
+        assert_address(&from_account.mint, ctx.mint.key, "from mint")?;
+
+        // to
+        assert_mut(ctx.to, "to")?;
+        assert_address(&to_account.mint, ctx.mint.key, "to mint")?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+        assert_address(ctx.authority.key, &mint_manager.authority, "authority")?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: RecallCtx, ix: RecallIx) -> ProgramResult {
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    let from_account = unpack_checked_token_account(ctx.from, Some("from"))?;
+    let to_account = unpack_checked_token_account(ctx.to, Some("to"))?;
+    if ix.amount == 0 || ix.amount > from_account.amount {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
+    if mint_manager.kind == MintManagerKind::NonFungible && ix.amount != 1 {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
+    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+
+    // thaw accounts
+    for (token_account_info, token_account) in [(ctx.from, from_account), (ctx.to, to_account)] {
+        if token_account.is_frozen() {
+            invoke_signed(
+                &spl_token_2022::instruction::thaw_account(
+                    ctx.token_program.key,
+                    token_account_info.key,
+                    ctx.mint.key,
+                    ctx.mint_manager.key,
+                    &[],
+                )?,
+                &[
+                    token_account_info.clone(),
+                    ctx.mint.clone(),
+                    ctx.mint_manager.clone(),
+                ],
+                &[&mint_manager_seeds
+                    .iter()
+                    .map(|s| s.as_slice())
+                    .collect::<Vec<&[u8]>>()],
+            )?;
+        }
+    }
+
+    // transfer as permanent delegate
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            ctx.token_program.key,
+            ctx.from.key,
+            ctx.mint.key,
+            ctx.to.key,
+            ctx.mint_manager.key,
+            &[],
+            ix.amount,
+            mint.decimals,
+        )?,
+        &[
+            ctx.from.clone(),
+            ctx.mint.clone(),
+            ctx.to.clone(),
+            ctx.mint_manager.clone(),
+        ],
+        &[&mint_manager_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()],
+    )?;
+
+    // freeze accounts
+    for token_account_info in [ctx.from, ctx.to] {
+        invoke_signed(
+            &spl_token_2022::instruction::freeze_account(
+                ctx.token_program.key,
+                token_account_info.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
+    // a fully recalled holder no longer keeps the token in use
+    if from_account.amount == ix.amount {
+        save_in_use_by(
+            ctx.mint_manager,
+            ctx.in_use_record,
+            None,
+            None,
+            InUsePurpose::Unspecified,
+            None,
+        )?;
+        save_additional_in_use_locks(ctx.mint_manager, ctx.in_use_record, vec![])?;
+    }
+
+    Ok(())
+}
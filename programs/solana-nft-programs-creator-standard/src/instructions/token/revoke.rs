@@ -1,11 +1,14 @@
 use crate::errors::ErrorCode;
+use crate::state::assert_holder_token_amount;
 use crate::state::assert_mint_manager_seeds;
+use crate::state::is_token_in_use;
+use crate::state::next_in_use_record;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
 use crate::utils::assert_address;
-use crate::utils::assert_amount;
 use crate::utils::assert_mut;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
 use crate::utils::assert_with_msg;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
@@ -27,16 +30,22 @@ pub fn revoke(
     mint: Pubkey,
     holder_token_account: Pubkey,
     holder: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(mint_manager, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new(holder_token_account, false),
+        AccountMeta::new_readonly(holder, true),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new_readonly(in_use_record, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(mint_manager, false),
-            AccountMeta::new_readonly(mint, false),
-            AccountMeta::new(holder_token_account, false),
-            AccountMeta::new_readonly(holder, true),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
+        accounts,
         data: CreatorStandardInstruction::Revoke.try_to_vec()?,
     })
 }
@@ -47,17 +56,21 @@ pub struct RevokeCtx<'a, 'info> {
     pub holder_token_account: &'a AccountInfo<'info>,
     pub holder: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> RevokeCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
             holder_token_account: next_account_info(account_iter)?,
             holder: next_account_info(account_iter)?,
             token_program: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
         };
         // deserializations
         let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
@@ -71,9 +84,9 @@ impl<'a, 'info> RevokeCtx<'a, 'info> {
 
         // holder_token_account
         assert_mut(ctx.holder_token_account, "holder_token_account")?;
-        assert_amount(
-            &holder_token_account.amount.to_string(),
-            "1",
+        assert_holder_token_amount(
+            &mint_manager,
+            holder_token_account.amount,
             "holder_token_account",
         )?;
         assert_address(
@@ -92,11 +105,18 @@ impl<'a, 'info> RevokeCtx<'a, 'info> {
             lazy_format!("{} must not be none", "holder_token_account"),
         )?;
 
+        // in_use_record
+        ctx.in_use_record = next_in_use_record(
+            &mint_manager,
+            ctx.holder_token_account.key,
+            &mut ctx.remaining_accounts,
+        )?;
+
         // holder
         assert_signer(ctx.holder, "holder")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         Ok(ctx)
     }
@@ -104,14 +124,14 @@ impl<'a, 'info> RevokeCtx<'a, 'info> {
 
 pub fn handler(ctx: RevokeCtx) -> ProgramResult {
     let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
-    if mint_manager.in_use_by.is_some() {
+    if is_token_in_use(&mint_manager, ctx.in_use_record)? {
         return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
     }
     let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
 
     // thaw account
     invoke_signed(
-        &spl_token::instruction::thaw_account(
+        &spl_token_2022::instruction::thaw_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
@@ -131,7 +151,7 @@ pub fn handler(ctx: RevokeCtx) -> ProgramResult {
 
     // revoke
     invoke_signed(
-        &spl_token::instruction::revoke(
+        &spl_token_2022::instruction::revoke(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.holder.key,
@@ -146,7 +166,7 @@ pub fn handler(ctx: RevokeCtx) -> ProgramResult {
 
     // freeze account
     invoke_signed(
-        &spl_token::instruction::freeze_account(
+        &spl_token_2022::instruction::freeze_account(
             ctx.token_program.key,
             ctx.holder_token_account.key,
             ctx.mint.key,
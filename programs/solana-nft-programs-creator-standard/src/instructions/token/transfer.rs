@@ -1,16 +1,25 @@
 use crate::errors::ErrorCode;
-use crate::state::allowlist_disallowlist;
+use crate::state::assert_mint_manager_ruleset;
 use crate::state::assert_mint_manager_seeds;
-use crate::state::is_base_program;
+use crate::state::assert_mint_metadata_seeds;
+use crate::state::assert_transfer_instructions;
+use crate::state::close_in_use_record;
+use crate::state::is_token_in_use;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
 use crate::state::CreatorStandardAccount;
 use crate::state::MintManager;
+use crate::state::MintManagerKind;
 use crate::state::Ruleset;
 use crate::utils::assert_address;
 use crate::utils::assert_mut;
 use crate::utils::assert_program_account;
 use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::utils::unpack_checked_mint_account;
 use crate::utils::unpack_checked_token_account;
 use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::account_info::next_account_info;
 use solana_program::account_info::AccountInfo;
@@ -20,10 +29,8 @@ use solana_program::instruction::Instruction;
 use solana_program::program::invoke_signed;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
-use solana_program::serialize_utils::read_u16;
 use solana_program::system_program;
 use solana_program::sysvar;
-use solana_program::sysvar::instructions::load_instruction_at_checked;
 
 #[allow(clippy::too_many_arguments)]
 pub fn transfer(
@@ -35,25 +42,39 @@ pub fn transfer(
     from: Pubkey,
     to: Pubkey,
     authority: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
+    amount: u64,
 ) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(mint_manager, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(mint_metadata, false),
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new(to, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
     Ok(Instruction {
         program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(mint_manager, false),
-            AccountMeta::new_readonly(mint, false),
-            AccountMeta::new_readonly(mint_metadata, false),
-            AccountMeta::new_readonly(ruleset, false),
-            AccountMeta::new(from, false),
-            AccountMeta::new(to, false),
-            AccountMeta::new_readonly(authority, true),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::instructions::id(), false),
-        ],
-        data: CreatorStandardInstruction::Transfer.try_to_vec()?,
+        accounts,
+        data: CreatorStandardInstruction::Transfer(TransferIx { amount }).try_to_vec()?,
     })
 }
 
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TransferIx {
+    pub amount: u64,
+}
+
 pub struct TransferCtx<'a, 'info> {
     pub mint_manager: &'a AccountInfo<'info>,
     pub mint: &'a AccountInfo<'info>,
@@ -65,13 +86,14 @@ pub struct TransferCtx<'a, 'info> {
     pub token_program: &'a AccountInfo<'info>,
     pub system_program: &'a AccountInfo<'info>,
     pub instructions: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
     pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> TransferCtx<'a, 'info> {
     pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
-        let ctx = Self {
+        let mut ctx = Self {
             mint_manager: next_account_info(account_iter)?,
             mint: next_account_info(account_iter)?,
             mint_metadata: next_account_info(account_iter)?,
@@ -82,6 +104,7 @@ impl<'a, 'info> TransferCtx<'a, 'info> {
             token_program: next_account_info(account_iter)?,
             system_program: next_account_info(account_iter)?,
             instructions: next_account_info(account_iter)?,
+            in_use_record: None,
             remaining_accounts: account_iter.collect(),
         };
         // deserializations
@@ -91,11 +114,23 @@ impl<'a, 'info> TransferCtx<'a, 'info> {
         assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
 
         // ruleset
-        assert_address(&mint_manager.ruleset, ctx.ruleset.key, "ruleset")?;
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
         assert_program_account(ctx.ruleset, Ruleset::hash())?;
 
+        // in_use_record
+        ctx.in_use_record =
+            next_in_use_record(&mint_manager, ctx.from.key, &mut ctx.remaining_accounts)?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
         ///// no checks for mint /////
 
+        // mint_metadata
+        assert_mint_metadata_seeds(ctx.mint.key, ctx.mint_metadata.key)?;
+
         // from
         assert_mut(ctx.from, "from")?;
         unpack_checked_token_account(ctx.from, Some("from"))?;
@@ -108,7 +143,7 @@ impl<'a, 'info> TransferCtx<'a, 'info> {
         assert_signer(ctx.authority, "authority")?;
 
         // token_program
-        assert_address(ctx.token_program.key, &spl_token::id(), "token_program")?;
+        assert_token_program(ctx.token_program, ctx.mint)?;
 
         // system_program
         assert_address(
@@ -128,55 +163,45 @@ impl<'a, 'info> TransferCtx<'a, 'info> {
     }
 }
 
-pub fn handler(ctx: TransferCtx) -> ProgramResult {
+pub fn handler(ctx: TransferCtx, ix: TransferIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    if ruleset.require_royalty_payment {
+        return Err(ProgramError::from(ErrorCode::RoyaltyPaymentRequired));
+    }
+    process_transfer(&ctx, ix.amount)
+}
+
+pub fn process_transfer(ctx: &TransferCtx, amount: u64) -> ProgramResult {
     let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
     let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
     let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
     let from_account = unpack_checked_token_account(ctx.from, Some("from"))?;
+    let to_account = unpack_checked_token_account(ctx.to, Some("to"))?;
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    if amount == 0 || amount > from_account.amount {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
+    if mint_manager.kind == MintManagerKind::NonFungible && amount != 1 {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
 
-    // instruction_sysvar
-    let instruction_sysvar = ctx.instructions.try_borrow_data()?;
-
-    let mut current: usize = 0;
-    let num_instructions =
-        read_u16(&mut current, &instruction_sysvar).expect("Invalid instruction");
-
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
     // check if the token is currenlty being used
-    if mint_manager.in_use_by.is_some() {
+    if is_token_in_use(&mint_manager, ctx.in_use_record)? {
         return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
     }
 
     /////////////// check allowed / disallowed ///////////////
     let remaining_accounts = &mut ctx.remaining_accounts.iter();
-    let [allowed_programs, disallowed_addresses] =
-        allowlist_disallowlist(&ruleset, remaining_accounts)?;
-
-    for i in 0..num_instructions {
-        let ix = load_instruction_at_checked(i.into(), ctx.instructions)
-            .expect("Failed to get instruction");
-
-        if !allowed_programs.is_empty()
-            && !is_base_program(&ix.program_id)
-            && !allowed_programs.contains(&ix.program_id.to_string())
-        {
-            return Err(ProgramError::from(ErrorCode::ProgramNotAllowed));
-        }
-
-        for account in ix.accounts {
-            if !disallowed_addresses.is_empty()
-                && (disallowed_addresses.contains(&ix.program_id.to_string())
-                    || disallowed_addresses.contains(&account.pubkey.to_string()))
-            {
-                return Err(ProgramError::from(ErrorCode::AddressDisallowed));
-            }
-        }
-    }
+    assert_transfer_instructions(&ruleset, ctx.instructions, remaining_accounts)?;
 
     ///////////////// handle transfer /////////////////
 
     // thaw account
     invoke_signed(
-        &spl_token::instruction::thaw_account(
+        &spl_token_2022::instruction::thaw_account(
             ctx.token_program.key,
             ctx.from.key,
             ctx.mint.key,
@@ -190,17 +215,42 @@ pub fn handler(ctx: TransferCtx) -> ProgramResult {
             .collect::<Vec<&[u8]>>()],
     )?;
 
+    // thaw destination if it already holds managed tokens
+    if to_account.is_frozen() {
+        invoke_signed(
+            &spl_token_2022::instruction::thaw_account(
+                ctx.token_program.key,
+                ctx.to.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[ctx.to.clone(), ctx.mint.clone(), ctx.mint_manager.clone()],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
     // transfer
     invoke_signed(
-        &spl_token::instruction::transfer(
+        &spl_token_2022::instruction::transfer_checked(
             ctx.token_program.key,
             ctx.from.key,
+            ctx.mint.key,
             ctx.to.key,
             ctx.authority.key,
             &[],
-            1,
+            amount,
+            mint.decimals,
         )?,
-        &[ctx.from.clone(), ctx.to.clone(), ctx.authority.clone()],
+        &[
+            ctx.from.clone(),
+            ctx.mint.clone(),
+            ctx.to.clone(),
+            ctx.authority.clone(),
+        ],
         &[&mint_manager_seeds
             .iter()
             .map(|s| s.as_slice())
@@ -209,7 +259,7 @@ pub fn handler(ctx: TransferCtx) -> ProgramResult {
 
     // freeze account
     invoke_signed(
-        &spl_token::instruction::freeze_account(
+        &spl_token_2022::instruction::freeze_account(
             ctx.token_program.key,
             ctx.to.key,
             ctx.mint.key,
@@ -223,6 +273,25 @@ pub fn handler(ctx: TransferCtx) -> ProgramResult {
             .collect::<Vec<&[u8]>>()],
     )?;
 
+    // refreeze from token account if it still holds tokens
+    if from_account.amount > amount {
+        invoke_signed(
+            &spl_token_2022::instruction::freeze_account(
+                ctx.token_program.key,
+                ctx.from.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[ctx.from.clone(), ctx.mint.clone(), ctx.mint_manager.clone()],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+        return Ok(());
+    }
+
     // close from token account
     if ctx.authority.key == &from_account.owner
         || from_account.close_authority.is_some()
@@ -233,7 +302,7 @@ pub fn handler(ctx: TransferCtx) -> ProgramResult {
     {
         // close account
         invoke_signed(
-            &spl_token::instruction::close_account(
+            &spl_token_2022::instruction::close_account(
                 ctx.token_program.key,
                 ctx.from.key,
                 ctx.authority.key,
@@ -247,6 +316,7 @@ pub fn handler(ctx: TransferCtx) -> ProgramResult {
             ],
             &[],
         )?;
+        close_in_use_record(ctx.in_use_record, ctx.authority)?;
     }
     ///////////////////////////////////////////////////
 
@@ -0,0 +1,324 @@
+use crate::errors::ErrorCode;
+use crate::state::allowlist_disallowlist;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::assert_mint_manager_seeds;
+use crate::state::assert_transfer_instructions;
+use crate::state::in_use_locks;
+use crate::state::is_base_program;
+use crate::state::is_in_use_expired;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::remove_in_use_lock;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::MintManagerKind;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::utils::unpack_checked_mint_account;
+use crate::utils::unpack_checked_token_account;
+use crate::utils::unpack_mint_permanent_delegate;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_by_in_user(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    ruleset: Pubkey,
+    from: Pubkey,
+    to: Pubkey,
+    recipient: Pubkey,
+    in_use_by_address: Pubkey,
+    token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(mint_manager, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new(to, false),
+        AccountMeta::new_readonly(recipient, false),
+        AccountMeta::new_readonly(in_use_by_address, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::TransferByInUser(TransferByInUserIx { amount })
+            .try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TransferByInUserIx {
+    pub amount: u64,
+}
+
+pub struct TransferByInUserCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub from: &'a AccountInfo<'info>,
+    pub to: &'a AccountInfo<'info>,
+    pub recipient: &'a AccountInfo<'info>,
+    pub in_use_by_address: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub instructions: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> TransferByInUserCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            from: next_account_info(account_iter)?,
+            to: next_account_info(account_iter)?,
+            recipient: next_account_info(account_iter)?,
+            in_use_by_address: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            instructions: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: account_iter.collect(),
+        };
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+        let from_account = unpack_checked_token_account(ctx.from, Some("from"))?;
+        let to_account = unpack_checked_token_account(ctx.to, Some("to"))?;
+
+        // mint_manager
+        assert_mut(ctx.mint_manager, "mint_manager")?;
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // in_use_record
+        ctx.in_use_record =
+            next_in_use_record(&mint_manager, ctx.from.key, &mut ctx.remaining_accounts)?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
+        // from
+        assert_mut(ctx.from, "from")?;
+        assert_address(&from_account.mint, ctx.mint.key, "from mint")?;
+        // without the mint manager as permanent delegate the in use address moves the token as delegate
+        if unpack_mint_permanent_delegate(ctx.mint)? != Some(*ctx.mint_manager.key) {
+            assert_address(
+                &Option::<Pubkey>::from(from_account.delegate).unwrap_or_default(),
+                ctx.in_use_by_address.key,
+                "from delegate",
+            )?;
+        }
+
+        // to
+        assert_mut(ctx.to, "to")?;
+        assert_address(&to_account.mint, ctx.mint.key, "to mint")?;
+
+        // recipient
+        assert_address(&to_account.owner, ctx.recipient.key, "to owner")?;
+
+        // in_use_by_address
+        assert_signer(ctx.in_use_by_address, "in_use_by_address")?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        // instructions
+        assert_address(
+            ctx.instructions.key,
+            &sysvar::instructions::id(),
+            "instructions",
+        )?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: TransferByInUserCtx, ix: TransferByInUserIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+    let mint = unpack_checked_mint_account(ctx.mint, Some("mint"))?;
+    let from_account = unpack_checked_token_account(ctx.from, Some("from"))?;
+    let to_account = unpack_checked_token_account(ctx.to, Some("to"))?;
+    if mint_manager.suspended {
+        return Err(ProgramError::from(ErrorCode::TokenSuspended));
+    }
+    // the in use address moves the token without a sale so it cannot pay royalties
+    if ruleset.require_royalty_payment {
+        return Err(ProgramError::from(ErrorCode::RoyaltyPaymentRequired));
+    }
+    if ix.amount == 0 || ix.amount > from_account.amount {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
+    if mint_manager.kind == MintManagerKind::NonFungible && ix.amount != 1 {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
+    // the signer must hold an active lock and no other active lock may be broken
+    let mut found = false;
+    for lock in in_use_locks(&mint_manager, ctx.in_use_record)? {
+        if is_in_use_expired(lock.in_use_until)? {
+            continue;
+        }
+        if &lock.in_use_by != ctx.in_use_by_address.key {
+            return Err(ProgramError::from(ErrorCode::TokenCurentlyInUse));
+        }
+        found = true;
+    }
+    if !found {
+        return Err(ProgramError::from(ErrorCode::InUseLockNotFound));
+    }
+    let mint_manager_seeds = assert_mint_manager_seeds(ctx.mint.key, ctx.mint_manager.key)?;
+    let remaining_accounts = ctx.remaining_accounts.iter();
+
+    /////////////// check allowed / disallowed ///////////////
+    assert_transfer_instructions(&ruleset, ctx.instructions, &mut remaining_accounts.clone())?;
+    // the recipient is held to the same lists as the programs in the transaction
+    let [allowed_programs, disallowed_addresses] =
+        allowlist_disallowlist(&ruleset, &mut remaining_accounts.clone())?;
+    if !allowed_programs.is_empty()
+        && !is_base_program(ctx.recipient.owner)
+        && !allowed_programs.contains(&ctx.recipient.owner.to_string())
+    {
+        return Err(ProgramError::from(ErrorCode::ProgramNotAllowed));
+    }
+
+    if !disallowed_addresses.is_empty()
+        && (disallowed_addresses.contains(&ctx.recipient.owner.to_string())
+            || disallowed_addresses.contains(&ctx.recipient.key.to_string())
+            || disallowed_addresses.contains(&ctx.to.key.to_string()))
+    {
+        return Err(ProgramError::from(ErrorCode::AddressDisallowed));
+    }
+    ////////////////////////////////////////////////////////////
+
+    // thaw accounts
+    for (token_account_info, token_account) in [(ctx.from, from_account), (ctx.to, to_account)] {
+        if token_account.is_frozen() {
+            invoke_signed(
+                &spl_token_2022::instruction::thaw_account(
+                    ctx.token_program.key,
+                    token_account_info.key,
+                    ctx.mint.key,
+                    ctx.mint_manager.key,
+                    &[],
+                )?,
+                &[
+                    token_account_info.clone(),
+                    ctx.mint.clone(),
+                    ctx.mint_manager.clone(),
+                ],
+                &[&mint_manager_seeds
+                    .iter()
+                    .map(|s| s.as_slice())
+                    .collect::<Vec<&[u8]>>()],
+            )?;
+        }
+    }
+
+    // transfer as permanent delegate or as the in use address holding the delegation
+    if unpack_mint_permanent_delegate(ctx.mint)? == Some(*ctx.mint_manager.key) {
+        invoke_signed(
+            &spl_token_2022::instruction::transfer_checked(
+                ctx.token_program.key,
+                ctx.from.key,
+                ctx.mint.key,
+                ctx.to.key,
+                ctx.mint_manager.key,
+                &[],
+                ix.amount,
+                mint.decimals,
+            )?,
+            &[
+                ctx.from.clone(),
+                ctx.mint.clone(),
+                ctx.to.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    } else {
+        invoke(
+            &spl_token_2022::instruction::transfer_checked(
+                ctx.token_program.key,
+                ctx.from.key,
+                ctx.mint.key,
+                ctx.to.key,
+                ctx.in_use_by_address.key,
+                &[],
+                ix.amount,
+                mint.decimals,
+            )?,
+            &[
+                ctx.from.clone(),
+                ctx.mint.clone(),
+                ctx.to.clone(),
+                ctx.in_use_by_address.clone(),
+            ],
+        )?;
+    }
+
+    // freeze accounts
+    for token_account_info in [ctx.from, ctx.to] {
+        invoke_signed(
+            &spl_token_2022::instruction::freeze_account(
+                ctx.token_program.key,
+                token_account_info.key,
+                ctx.mint.key,
+                ctx.mint_manager.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                ctx.mint.clone(),
+                ctx.mint_manager.clone(),
+            ],
+            &[&mint_manager_seeds
+                .iter()
+                .map(|s| s.as_slice())
+                .collect::<Vec<&[u8]>>()],
+        )?;
+    }
+
+    remove_in_use_lock(
+        ctx.mint_manager,
+        ctx.in_use_record,
+        ctx.in_use_by_address.key,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use crate::errors::ErrorCode;
+use crate::instructions::token::transfer::process_transfer;
+use crate::instructions::token::transfer::TransferCtx;
+use crate::state::assert_mint_manager_ruleset;
+use crate::state::assert_mint_metadata_seeds;
+use crate::state::next_collection_manager;
+use crate::state::next_in_use_record;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintManager;
+use crate::state::MintMetadata;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_program_account;
+use crate::utils::assert_signer;
+use crate::utils::assert_token_program;
+use crate::utils::calculate_creator_amount;
+use crate::utils::calculate_royalty_amount;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::next_account_info;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::system_program;
+use solana_program::sysvar;
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_payment(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    mint_metadata: Pubkey,
+    ruleset: Pubkey,
+    from: Pubkey,
+    to: Pubkey,
+    authority: Pubkey,
+    token_program: Pubkey,
+    buyer: Pubkey,
+    in_use_record: Option<Pubkey>,
+    creators: Vec<Pubkey>,
+    price: u64,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(mint_manager, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(mint_metadata, false),
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new(to, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(buyer, true),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
+    for creator in creators {
+        accounts.push(AccountMeta::new(creator, false));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::TransferWithPayment(TransferWithPaymentIx {
+            price,
+            amount,
+        })
+        .try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TransferWithPaymentIx {
+    pub price: u64,
+    pub amount: u64,
+}
+
+pub struct TransferWithPaymentCtx<'a, 'info> {
+    pub mint_manager: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub mint_metadata: &'a AccountInfo<'info>,
+    pub ruleset: &'a AccountInfo<'info>,
+    pub from: &'a AccountInfo<'info>,
+    pub to: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub instructions: &'a AccountInfo<'info>,
+    pub buyer: &'a AccountInfo<'info>,
+    pub in_use_record: Option<&'a AccountInfo<'info>>,
+    pub remaining_accounts: Vec<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> TransferWithPaymentCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let (ctx, _) = Self::load_with_extra_accounts(accounts, 0)?;
+        Ok(ctx)
+    }
+
+    // payment variants take `extra_accounts` of their own between buyer and the remaining accounts
+    pub fn load_with_extra_accounts(
+        accounts: &'a [AccountInfo<'info>],
+        extra_accounts: usize,
+    ) -> Result<(Self, Vec<&'a AccountInfo<'info>>), ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let mut ctx = Self {
+            mint_manager: next_account_info(account_iter)?,
+            mint: next_account_info(account_iter)?,
+            mint_metadata: next_account_info(account_iter)?,
+            ruleset: next_account_info(account_iter)?,
+            from: next_account_info(account_iter)?,
+            to: next_account_info(account_iter)?,
+            authority: next_account_info(account_iter)?,
+            token_program: next_account_info(account_iter)?,
+            system_program: next_account_info(account_iter)?,
+            instructions: next_account_info(account_iter)?,
+            buyer: next_account_info(account_iter)?,
+            in_use_record: None,
+            remaining_accounts: vec![],
+        };
+        let extra_accounts = (0..extra_accounts)
+            .map(|_| next_account_info(account_iter))
+            .collect::<Result<Vec<_>, _>>()?;
+        ctx.remaining_accounts = account_iter.collect();
+        // deserializations
+        let mint_manager: MintManager = MintManager::from_account_info(ctx.mint_manager)?;
+
+        // mint_manager
+        assert_address(&mint_manager.mint, ctx.mint.key, "mint_manager mint")?;
+
+        // ruleset
+        let collection_manager =
+            next_collection_manager(&mint_manager, &mut ctx.remaining_accounts)?;
+        assert_mint_manager_ruleset(&mint_manager, ctx.ruleset, collection_manager)?;
+        assert_program_account(ctx.ruleset, Ruleset::hash())?;
+
+        // in_use_record
+        ctx.in_use_record =
+            next_in_use_record(&mint_manager, ctx.from.key, &mut ctx.remaining_accounts)?;
+        if let Some(in_use_record) = ctx.in_use_record {
+            assert_mut(in_use_record, "in_use_record")?;
+        }
+
+        ///// no checks for mint /////
+
+        // mint_metadata
+        assert_mint_metadata_seeds(ctx.mint.key, ctx.mint_metadata.key)?;
+
+        // from
+        assert_mut(ctx.from, "from")?;
+        unpack_checked_token_account(ctx.from, Some("from"))?;
+
+        // to
+        assert_mut(ctx.to, "to")?;
+        unpack_checked_token_account(ctx.to, Some("to"))?;
+
+        // authority
+        assert_signer(ctx.authority, "authority")?;
+
+        // token_program
+        assert_token_program(ctx.token_program, ctx.mint)?;
+
+        // system_program
+        assert_address(
+            ctx.system_program.key,
+            &system_program::id(),
+            "system_program",
+        )?;
+
+        // instructions
+        assert_address(
+            ctx.instructions.key,
+            &sysvar::instructions::id(),
+            "instructions",
+        )?;
+
+        // buyer
+        assert_signer(ctx.buyer, "buyer")?;
+        assert_mut(ctx.buyer, "buyer")?;
+
+        Ok((ctx, extra_accounts))
+    }
+}
+
+// checks the price and pays each creator of the mint metadata its share of the royalty. each
+// creator takes `accounts_per_creator` remaining accounts after the ruleset extensions, starting
+// with the creator itself, and `pay_creator` receives them along with the creator amount
+pub fn pay_creator_royalties<'a, 'info>(
+    ruleset: &Ruleset,
+    mint_metadata: &MintMetadata,
+    remaining_accounts: &[&'a AccountInfo<'info>],
+    price: u64,
+    accounts_per_creator: usize,
+    mut pay_creator: impl FnMut(&[&'a AccountInfo<'info>], u64) -> ProgramResult,
+) -> ProgramResult {
+    // a zero price would move the token without any royalty
+    if price == 0 {
+        return Err(ProgramError::from(ErrorCode::InvalidAmount));
+    }
+
+    let creator_infos = &mut remaining_accounts.iter().skip(ruleset.extensions.len());
+    let royalty = calculate_royalty_amount(price, mint_metadata.data.seller_fee_basis_points)?;
+
+    if let Some(creators) = &mint_metadata.data.creators {
+        for creator in creators {
+            let creator_accounts = creator_infos
+                .by_ref()
+                .take(accounts_per_creator)
+                .copied()
+                .collect::<Vec<_>>();
+            if creator_accounts.len() < accounts_per_creator {
+                return Err(ProgramError::from(ErrorCode::NotEnoughRemainingAccounts));
+            }
+            if creator_accounts[0].key != &creator.address {
+                return Err(ProgramError::from(ErrorCode::InvalidRoyaltyCreator));
+            }
+            pay_creator(
+                &creator_accounts,
+                calculate_creator_amount(royalty, creator.share)?,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub fn handler(ctx: TransferWithPaymentCtx, ix: TransferWithPaymentIx) -> ProgramResult {
+    let ruleset: Ruleset = Ruleset::from_account_info(ctx.ruleset)?;
+    let mint_metadata = MintMetadata::from_account_info(ctx.mint_metadata)?;
+
+    ///////////////// pay royalties /////////////////
+    pay_creator_royalties(
+        &ruleset,
+        &mint_metadata,
+        &ctx.remaining_accounts,
+        ix.price,
+        1,
+        |creator_accounts, creator_amount| {
+            let creator_info = creator_accounts[0];
+            assert_mut(creator_info, "creator")?;
+            if creator_amount == 0 {
+                return Ok(());
+            }
+            invoke(
+                &system_instruction::transfer(ctx.buyer.key, creator_info.key, creator_amount),
+                &[
+                    ctx.buyer.clone(),
+                    creator_info.clone(),
+                    ctx.system_program.clone(),
+                ],
+            )
+        },
+    )?;
+    ///////////////////////////////////////////////////
+
+    process_transfer(
+        &TransferCtx {
+            mint_manager: ctx.mint_manager,
+            mint: ctx.mint,
+            mint_metadata: ctx.mint_metadata,
+            ruleset: ctx.ruleset,
+            from: ctx.from,
+            to: ctx.to,
+            authority: ctx.authority,
+            token_program: ctx.token_program,
+            system_program: ctx.system_program,
+            instructions: ctx.instructions,
+            in_use_record: ctx.in_use_record,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        ix.amount,
+    )
+}
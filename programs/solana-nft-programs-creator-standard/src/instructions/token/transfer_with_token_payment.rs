@@ -0,0 +1,245 @@
+use crate::errors::ErrorCode;
+use crate::instructions::token::transfer::process_transfer;
+use crate::instructions::token::transfer::TransferCtx;
+use crate::instructions::token::transfer_with_payment::pay_creator_royalties;
+use crate::instructions::token::transfer_with_payment::TransferWithPaymentCtx;
+use crate::state::CreatorStandardAccount;
+use crate::state::MintMetadata;
+use crate::state::Ruleset;
+use crate::utils::assert_address;
+use crate::utils::assert_mut;
+use crate::utils::assert_token_program;
+use crate::utils::unpack_checked_mint_account;
+use crate::utils::unpack_checked_token_account;
+use crate::CreatorStandardInstruction;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_token_payment(
+    program_id: Pubkey,
+    mint_manager: Pubkey,
+    mint: Pubkey,
+    mint_metadata: Pubkey,
+    ruleset: Pubkey,
+    from: Pubkey,
+    to: Pubkey,
+    authority: Pubkey,
+    token_program: Pubkey,
+    buyer: Pubkey,
+    payment_mint: Pubkey,
+    buyer_token_account: Pubkey,
+    payment_token_program: Pubkey,
+    in_use_record: Option<Pubkey>,
+    creators: Vec<Pubkey>,
+    price: u64,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(mint_manager, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(mint_metadata, false),
+        AccountMeta::new_readonly(ruleset, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new(to, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(buyer, true),
+        AccountMeta::new_readonly(payment_mint, false),
+        AccountMeta::new(buyer_token_account, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(payment_token_program, false),
+    ];
+    if let Some(in_use_record) = in_use_record {
+        accounts.push(AccountMeta::new(in_use_record, false));
+    }
+    for creator in creators {
+        accounts.push(AccountMeta::new_readonly(creator, false));
+        accounts.push(AccountMeta::new(
+            get_associated_token_address_with_program_id(
+                &creator,
+                &payment_mint,
+                &payment_token_program,
+            ),
+            false,
+        ));
+    }
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data: CreatorStandardInstruction::TransferWithTokenPayment(TransferWithTokenPaymentIx {
+            price,
+            amount,
+        })
+        .try_to_vec()?,
+    })
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TransferWithTokenPaymentIx {
+    pub price: u64,
+    pub amount: u64,
+}
+
+pub struct TransferWithTokenPaymentCtx<'a, 'info> {
+    pub transfer: TransferWithPaymentCtx<'a, 'info>,
+    pub payment_mint: &'a AccountInfo<'info>,
+    pub buyer_token_account: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub payment_token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TransferWithTokenPaymentCtx<'a, 'info> {
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let (transfer, extra_accounts) =
+            TransferWithPaymentCtx::load_with_extra_accounts(accounts, 4)?;
+        let ctx = Self {
+            transfer,
+            payment_mint: extra_accounts[0],
+            buyer_token_account: extra_accounts[1],
+            associated_token_program: extra_accounts[2],
+            payment_token_program: extra_accounts[3],
+        };
+        // deserializations
+        let ruleset: Ruleset = Ruleset::from_account_info(ctx.transfer.ruleset)?;
+        let buyer_token_account =
+            unpack_checked_token_account(ctx.buyer_token_account, Some("buyer_token_account"))?;
+
+        // payment_mint
+        unpack_checked_mint_account(ctx.payment_mint, Some("payment_mint"))?;
+        if !ruleset.payment_mints.contains(ctx.payment_mint.key) {
+            return Err(ProgramError::from(ErrorCode::PaymentMintNotAllowed));
+        }
+
+        // buyer_token_account
+        assert_mut(ctx.buyer_token_account, "buyer_token_account")?;
+        assert_address(
+            &buyer_token_account.mint,
+            ctx.payment_mint.key,
+            "buyer_token_account mint",
+        )?;
+        assert_address(
+            &buyer_token_account.owner,
+            ctx.transfer.buyer.key,
+            "buyer_token_account owner",
+        )?;
+
+        // associated_token_program
+        assert_address(
+            ctx.associated_token_program.key,
+            &spl_associated_token_account::id(),
+            "associated_token_program",
+        )?;
+
+        // payment_token_program
+        assert_token_program(ctx.payment_token_program, ctx.payment_mint)?;
+
+        Ok(ctx)
+    }
+}
+
+pub fn handler(ctx: TransferWithTokenPaymentCtx, ix: TransferWithTokenPaymentIx) -> ProgramResult {
+    let transfer = &ctx.transfer;
+    let ruleset: Ruleset = Ruleset::from_account_info(transfer.ruleset)?;
+    let mint_metadata = MintMetadata::from_account_info(transfer.mint_metadata)?;
+    let payment_mint = unpack_checked_mint_account(ctx.payment_mint, Some("payment_mint"))?;
+
+    ///////////////// pay royalties /////////////////
+    // each creator is followed by its payment mint token account
+    pay_creator_royalties(
+        &ruleset,
+        &mint_metadata,
+        &transfer.remaining_accounts,
+        ix.price,
+        2,
+        |creator_accounts, creator_amount| {
+            let creator_info = creator_accounts[0];
+            let creator_token_account_info = creator_accounts[1];
+            assert_mut(creator_token_account_info, "creator_token_account")?;
+            assert_address(
+                creator_token_account_info.key,
+                &get_associated_token_address_with_program_id(
+                    creator_info.key,
+                    ctx.payment_mint.key,
+                    ctx.payment_token_program.key,
+                ),
+                "creator_token_account",
+            )?;
+            if creator_amount == 0 {
+                return Ok(());
+            }
+
+            // create creator token account if missing
+            invoke(
+                &create_associated_token_account_idempotent(
+                    transfer.buyer.key,
+                    creator_info.key,
+                    ctx.payment_mint.key,
+                    ctx.payment_token_program.key,
+                ),
+                &[
+                    transfer.buyer.clone(),
+                    creator_token_account_info.clone(),
+                    creator_info.clone(),
+                    ctx.payment_mint.clone(),
+                    transfer.system_program.clone(),
+                    ctx.payment_token_program.clone(),
+                    ctx.associated_token_program.clone(),
+                ],
+            )?;
+
+            // transfer royalty
+            invoke(
+                &spl_token_2022::instruction::transfer_checked(
+                    ctx.payment_token_program.key,
+                    ctx.buyer_token_account.key,
+                    ctx.payment_mint.key,
+                    creator_token_account_info.key,
+                    transfer.buyer.key,
+                    &[],
+                    creator_amount,
+                    payment_mint.decimals,
+                )?,
+                &[
+                    ctx.buyer_token_account.clone(),
+                    ctx.payment_mint.clone(),
+                    creator_token_account_info.clone(),
+                    transfer.buyer.clone(),
+                ],
+            )
+        },
+    )?;
+    ///////////////////////////////////////////////////
+
+    process_transfer(
+        &TransferCtx {
+            mint_manager: ctx.transfer.mint_manager,
+            mint: ctx.transfer.mint,
+            mint_metadata: ctx.transfer.mint_metadata,
+            ruleset: ctx.transfer.ruleset,
+            from: ctx.transfer.from,
+            to: ctx.transfer.to,
+            authority: ctx.transfer.authority,
+            token_program: ctx.transfer.token_program,
+            system_program: ctx.transfer.system_program,
+            instructions: ctx.transfer.instructions,
+            in_use_record: ctx.transfer.in_use_record,
+            remaining_accounts: ctx.transfer.remaining_accounts,
+        },
+        ix.amount,
+    )
+}
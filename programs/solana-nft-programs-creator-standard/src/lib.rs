@@ -52,21 +52,22 @@ pub enum CreatorStandardInstruction {
     #[account(7, writable, signer, name = "payer")]
     #[account(8, name = "token_program", desc = "Token program")]
     #[account(9, name = "system_program", desc = "System program")]
-    InitMintManager,
+    InitMintManager(InitMintManagerIx),
 
     #[account(0, writable, name = "mint_manager")]
     #[account(1, name = "ruleset")]
     #[account(2, signer, name = "authority")]
     #[account(3, writable, signer, name = "payer")]
     #[account(4, name = "system_program", desc = "System program")]
+    #[account(5, name = "mint_metadata", desc = "Mint metadata")]
     UpdateMintManager(UpdateMintManagerIx),
 
     #[account(0, writable, name = "mint_manager")]
     #[account(1, name = "ruleset")]
     #[account(2, name = "in_use_by_address")]
-    #[account(3, signer, name = "holder")]
+    #[account(3, signer, name = "holder", desc = "Holder token account owner or its current delegate")]
     #[account(4, name = "holder_token_account")]
-    SetInUseBy,
+    SetInUseBy(SetInUseByIx),
 
     #[account(0, writable, name = "mint_manager")]
     #[account(1, signer, name = "user")]
@@ -98,7 +99,7 @@ pub enum CreatorStandardInstruction {
     #[account(3, writable, signer, name = "holder")]
     #[account(4, name = "token_program")]
     #[account(5, name = "system_program")]
-    Burn,
+    Burn(BurnIx),
 
     #[account(0, name = "mint_manager")]
     #[account(1, writable, name = "mint")]
@@ -134,7 +135,7 @@ pub enum CreatorStandardInstruction {
     #[account(7, name = "token_program")]
     #[account(8, name = "system_program")]
     #[account(9, name = "instructions")]
-    Transfer,
+    Transfer(TransferIx),
 
     #[account(0, writable, name = "mint_manager")]
     #[account(1, writable, name = "mint")]
@@ -145,6 +146,212 @@ pub enum CreatorStandardInstruction {
     #[account(6, name = "token_program", desc = "Token program")]
     #[account(7, name = "system_program", desc = "System program")]
     CloseMintManager,
+
+    #[account(0, writable, name = "ruleset")]
+    #[account(1, signer, name = "authority")]
+    LockRuleset,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, signer, name = "authority")]
+    RenounceMintManagerAuthority,
+
+    #[account(0, name = "source_ruleset")]
+    #[account(1, writable, name = "ruleset")]
+    #[account(2, signer, name = "authority")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    CloneRuleset(CloneRulesetIx),
+
+    #[account(0, name = "mint_manager")]
+    #[account(1, name = "mint")]
+    #[account(2, name = "mint_metadata", desc = "Mint metadata")]
+    #[account(3, name = "ruleset")]
+    #[account(4, writable, name = "from")]
+    #[account(5, writable, name = "to")]
+    #[account(6, signer, name = "authority")]
+    #[account(7, name = "token_program")]
+    #[account(8, name = "system_program")]
+    #[account(9, name = "instructions")]
+    #[account(10, writable, signer, name = "buyer")]
+    TransferWithPayment(TransferWithPaymentIx),
+
+    #[account(0, name = "mint_manager")]
+    #[account(1, name = "mint")]
+    #[account(2, name = "mint_metadata", desc = "Mint metadata")]
+    #[account(3, name = "ruleset")]
+    #[account(4, writable, name = "from")]
+    #[account(5, writable, name = "to")]
+    #[account(6, signer, name = "authority")]
+    #[account(7, name = "token_program")]
+    #[account(8, name = "system_program")]
+    #[account(9, name = "instructions")]
+    #[account(10, writable, signer, name = "buyer")]
+    #[account(11, name = "payment_mint")]
+    #[account(12, writable, name = "buyer_token_account")]
+    #[account(13, name = "associated_token_program")]
+    #[account(14, name = "payment_token_program")]
+    TransferWithTokenPayment(TransferWithTokenPaymentIx),
+
+    #[account(0, name = "ruleset")]
+    #[account(1, signer, name = "token_authority")]
+    #[account(2, name = "authority")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "token_program")]
+    #[account(5, name = "system_program")]
+    BatchInitMintManager,
+
+    #[account(0, writable, name = "collection_manager")]
+    #[account(1, name = "collection")]
+    #[account(2, name = "collection_metadata", desc = "Collection mint metadata")]
+    #[account(3, name = "ruleset")]
+    #[account(4, signer, name = "authority")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "system_program", desc = "System program")]
+    InitCollectionManager,
+
+    #[account(0, writable, name = "collection_manager")]
+    #[account(1, name = "ruleset")]
+    #[account(2, signer, name = "authority")]
+    UpdateCollectionManager(UpdateCollectionManagerIx),
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, writable, signer, name = "mint")]
+    #[account(2, writable, name = "mint_metadata")]
+    #[account(3, name = "ruleset")]
+    #[account(4, writable, name = "target_token_account")]
+    #[account(5, name = "target")]
+    #[account(6, signer, name = "authority")]
+    #[account(7, writable, signer, name = "payer")]
+    #[account(8, name = "token_program", desc = "Token program")]
+    #[account(9, name = "associated_token_program", desc = "Associated token program")]
+    #[account(10, name = "token_metadata_program", desc = "Token metadata program")]
+    #[account(11, name = "system_program", desc = "System program")]
+    #[account(12, name = "rent", desc = "Rent sysvar")]
+    MintNewToken(MintNewTokenIx),
+
+    #[account(0, writable, name = "in_use_record")]
+    #[account(1, name = "mint_manager")]
+    #[account(2, name = "holder_token_account")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program", desc = "System program")]
+    InitInUseRecord,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, signer, name = "authority")]
+    DisableRecall,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, name = "mint")]
+    #[account(2, name = "ruleset")]
+    #[account(3, writable, name = "from")]
+    #[account(4, writable, name = "to")]
+    #[account(5, signer, name = "authority")]
+    #[account(6, name = "token_program")]
+    Recall(RecallIx),
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, name = "ruleset")]
+    #[account(2, signer, name = "authority")]
+    SuspendToken,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, signer, name = "authority")]
+    UnsuspendToken,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, name = "ruleset")]
+    #[account(2, name = "in_use_by_address")]
+    #[account(3, signer, name = "holder")]
+    #[account(4, name = "holder_token_account")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "system_program")]
+    AddInUseLock(AddInUseLockIx),
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, name = "ruleset")]
+    #[account(2, name = "in_use_by_address")]
+    #[account(3, signer, name = "holder")]
+    #[account(4, name = "holder_token_account")]
+    RequestInUseRelease,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, signer, name = "user")]
+    RenewInUseBy,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, name = "ruleset")]
+    #[account(2, name = "in_use_by_address")]
+    #[account(3, signer, name = "holder")]
+    #[account(4, name = "holder_token_account")]
+    ForceRemoveInUseBy,
+
+    #[account(0, name = "ruleset")]
+    #[account(1, name = "in_use_by_address")]
+    #[account(2, signer, name = "authority")]
+    AuthorityRemoveInUseBy,
+
+    // rental
+    #[account(0, writable, name = "rental")]
+    #[account(1, writable, name = "mint_manager")]
+    #[account(2, name = "mint")]
+    #[account(3, name = "ruleset")]
+    #[account(4, writable, name = "owner_token_account")]
+    #[account(5, writable, signer, name = "owner")]
+    #[account(6, name = "token_program")]
+    #[account(7, name = "system_program")]
+    #[account(8, name = "instructions")]
+    ListRental(ListRentalIx),
+
+    #[account(0, writable, name = "rental")]
+    #[account(1, writable, name = "mint_manager")]
+    #[account(2, name = "mint")]
+    #[account(3, writable, name = "owner_token_account")]
+    #[account(4, writable, signer, name = "owner")]
+    #[account(5, name = "token_program")]
+    CancelRental,
+
+    #[account(0, writable, name = "rental")]
+    #[account(1, writable, name = "mint_manager")]
+    #[account(2, name = "mint")]
+    #[account(3, name = "ruleset")]
+    #[account(4, writable, name = "owner_token_account")]
+    #[account(5, writable, name = "renter_token_account")]
+    #[account(6, writable, name = "owner")]
+    #[account(7, writable, signer, name = "renter")]
+    #[account(8, name = "token_program")]
+    #[account(9, name = "system_program")]
+    #[account(10, name = "instructions")]
+    RentToken,
+
+    #[account(0, writable, name = "rental")]
+    #[account(1, writable, name = "mint_manager")]
+    #[account(2, name = "mint")]
+    #[account(3, writable, name = "owner_token_account")]
+    #[account(4, writable, name = "renter_token_account")]
+    #[account(5, writable, name = "owner")]
+    #[account(6, name = "token_program")]
+    ReclaimRental,
+
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, name = "mint")]
+    #[account(2, name = "ruleset")]
+    #[account(3, writable, name = "from")]
+    #[account(4, writable, name = "to")]
+    #[account(5, name = "recipient", desc = "Owner of the to token account")]
+    #[account(6, signer, name = "in_use_by_address")]
+    #[account(7, name = "token_program")]
+    #[account(8, name = "instructions")]
+    TransferByInUser(TransferByInUserIx),
+
+    // migration
+    #[account(0, writable, name = "ruleset")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program", desc = "System program")]
+    MigrateRuleset,
+    #[account(0, writable, name = "mint_manager")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program", desc = "System program")]
+    MigrateMintManager,
 }
 
 pub fn process_instruction(
@@ -169,20 +376,20 @@ pub fn process_instruction(
             let ctx = CloseRulesetCtx::load(accounts)?;
             instructions::ruleset::close_ruleset::handler(ctx)
         }
-        CreatorStandardInstruction::InitMintManager => {
+        CreatorStandardInstruction::InitMintManager(ix) => {
             msg!("CreatorStandardInstruction::InitMintManager");
             let ctx = InitMintManagerCtx::load(accounts)?;
-            instructions::mint_manager::init_mint_manager::handler(ctx)
+            instructions::mint_manager::init_mint_manager::handler(ctx, ix)
         }
         CreatorStandardInstruction::UpdateMintManager(ix) => {
             msg!("CreatorStandardInstruction::UpdateMintManager");
             let ctx = UpdateMintManagerCtx::load(accounts)?;
             instructions::mint_manager::update_mint_manager::handler(ctx, ix)
         }
-        CreatorStandardInstruction::SetInUseBy => {
+        CreatorStandardInstruction::SetInUseBy(ix) => {
             msg!("CreatorStandardInstruction::SetInUseBy");
             let ctx = mint_manager::SetInUseByCtx::load(accounts)?;
-            instructions::mint_manager::set_in_use_by::handler(ctx)
+            instructions::mint_manager::set_in_use_by::handler(ctx, ix)
         }
         CreatorStandardInstruction::RemoveInUseBy => {
             msg!("CreatorStandardInstruction::RemoveInUseBy");
@@ -199,10 +406,10 @@ pub fn process_instruction(
             let ctx = ApproveAndSetInUseByCtx::load(accounts)?;
             instructions::token::approve_and_set_in_use_by::handler(ctx, ix)
         }
-        CreatorStandardInstruction::Burn => {
+        CreatorStandardInstruction::Burn(ix) => {
             msg!("CreatorStandardInstruction::Burn");
             let ctx = BurnCtx::load(accounts)?;
-            instructions::token::burn::handler(ctx)
+            instructions::token::burn::handler(ctx, ix)
         }
         CreatorStandardInstruction::Close => {
             msg!("CreatorStandardInstruction::Close");
@@ -219,15 +426,145 @@ pub fn process_instruction(
             let ctx = RevokeCtx::load(accounts)?;
             instructions::token::revoke::handler(ctx)
         }
-        CreatorStandardInstruction::Transfer => {
+        CreatorStandardInstruction::Transfer(ix) => {
             msg!("CreatorStandardInstruction::Transfer");
             let ctx = TransferCtx::load(accounts)?;
-            instructions::token::transfer::handler(ctx)
+            instructions::token::transfer::handler(ctx, ix)
         }
         CreatorStandardInstruction::CloseMintManager => {
             msg!("CreatorStandardInstruction::CloseMintManager");
             let ctx = CloseMintManagerCtx::load(accounts)?;
             instructions::mint_manager::close_mint_manager::handler(ctx)
         }
+        CreatorStandardInstruction::LockRuleset => {
+            msg!("CreatorStandardInstruction::LockRuleset");
+            let ctx = LockRulesetCtx::load(accounts)?;
+            instructions::ruleset::lock_ruleset::handler(ctx)
+        }
+        CreatorStandardInstruction::RenounceMintManagerAuthority => {
+            msg!("CreatorStandardInstruction::RenounceMintManagerAuthority");
+            let ctx = RenounceMintManagerAuthorityCtx::load(accounts)?;
+            instructions::mint_manager::renounce_mint_manager_authority::handler(ctx)
+        }
+        CreatorStandardInstruction::CloneRuleset(ix) => {
+            msg!("CreatorStandardInstruction::CloneRuleset");
+            let ctx = CloneRulesetCtx::load(accounts)?;
+            instructions::ruleset::clone_ruleset::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::TransferWithPayment(ix) => {
+            msg!("CreatorStandardInstruction::TransferWithPayment");
+            let ctx = TransferWithPaymentCtx::load(accounts)?;
+            instructions::token::transfer_with_payment::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::TransferWithTokenPayment(ix) => {
+            msg!("CreatorStandardInstruction::TransferWithTokenPayment");
+            let ctx = TransferWithTokenPaymentCtx::load(accounts)?;
+            instructions::token::transfer_with_token_payment::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::BatchInitMintManager => {
+            msg!("CreatorStandardInstruction::BatchInitMintManager");
+            let ctx = BatchInitMintManagerCtx::load(accounts)?;
+            instructions::mint_manager::batch_init_mint_manager::handler(ctx)
+        }
+        CreatorStandardInstruction::InitCollectionManager => {
+            msg!("CreatorStandardInstruction::InitCollectionManager");
+            let ctx = InitCollectionManagerCtx::load(accounts)?;
+            instructions::collection_manager::init_collection_manager::handler(ctx)
+        }
+        CreatorStandardInstruction::UpdateCollectionManager(ix) => {
+            msg!("CreatorStandardInstruction::UpdateCollectionManager");
+            let ctx = UpdateCollectionManagerCtx::load(accounts)?;
+            instructions::collection_manager::update_collection_manager::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::MintNewToken(ix) => {
+            msg!("CreatorStandardInstruction::MintNewToken");
+            let ctx = MintNewTokenCtx::load(accounts)?;
+            instructions::mint_manager::mint_new_token::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::InitInUseRecord => {
+            msg!("CreatorStandardInstruction::InitInUseRecord");
+            let ctx = InitInUseRecordCtx::load(accounts)?;
+            instructions::mint_manager::init_in_use_record::handler(ctx)
+        }
+        CreatorStandardInstruction::DisableRecall => {
+            msg!("CreatorStandardInstruction::DisableRecall");
+            let ctx = DisableRecallCtx::load(accounts)?;
+            instructions::mint_manager::disable_recall::handler(ctx)
+        }
+        CreatorStandardInstruction::Recall(ix) => {
+            msg!("CreatorStandardInstruction::Recall");
+            let ctx = RecallCtx::load(accounts)?;
+            instructions::token::recall::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::SuspendToken => {
+            msg!("CreatorStandardInstruction::SuspendToken");
+            let ctx = SuspendTokenCtx::load(accounts)?;
+            instructions::mint_manager::suspend_token::handler(ctx)
+        }
+        CreatorStandardInstruction::UnsuspendToken => {
+            msg!("CreatorStandardInstruction::UnsuspendToken");
+            let ctx = UnsuspendTokenCtx::load(accounts)?;
+            instructions::mint_manager::unsuspend_token::handler(ctx)
+        }
+        CreatorStandardInstruction::AddInUseLock(ix) => {
+            msg!("CreatorStandardInstruction::AddInUseLock");
+            let ctx = AddInUseLockCtx::load(accounts)?;
+            instructions::mint_manager::add_in_use_lock::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::RequestInUseRelease => {
+            msg!("CreatorStandardInstruction::RequestInUseRelease");
+            let ctx = RequestInUseReleaseCtx::load(accounts)?;
+            instructions::mint_manager::request_in_use_release::handler(ctx)
+        }
+        CreatorStandardInstruction::RenewInUseBy => {
+            msg!("CreatorStandardInstruction::RenewInUseBy");
+            let ctx = RenewInUseByCtx::load(accounts)?;
+            instructions::mint_manager::renew_in_use_by::handler(ctx)
+        }
+        CreatorStandardInstruction::ForceRemoveInUseBy => {
+            msg!("CreatorStandardInstruction::ForceRemoveInUseBy");
+            let ctx = ForceRemoveInUseByCtx::load(accounts)?;
+            instructions::mint_manager::force_remove_in_use_by::handler(ctx)
+        }
+        CreatorStandardInstruction::AuthorityRemoveInUseBy => {
+            msg!("CreatorStandardInstruction::AuthorityRemoveInUseBy");
+            let ctx = AuthorityRemoveInUseByCtx::load(accounts)?;
+            instructions::mint_manager::authority_remove_in_use_by::handler(ctx)
+        }
+        CreatorStandardInstruction::ListRental(ix) => {
+            msg!("CreatorStandardInstruction::ListRental");
+            let ctx = ListRentalCtx::load(accounts)?;
+            instructions::rental::list_rental::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::CancelRental => {
+            msg!("CreatorStandardInstruction::CancelRental");
+            let ctx = CancelRentalCtx::load(accounts)?;
+            instructions::rental::cancel_rental::handler(ctx)
+        }
+        CreatorStandardInstruction::RentToken => {
+            msg!("CreatorStandardInstruction::RentToken");
+            let ctx = RentTokenCtx::load(accounts)?;
+            instructions::rental::rent_token::handler(ctx)
+        }
+        CreatorStandardInstruction::ReclaimRental => {
+            msg!("CreatorStandardInstruction::ReclaimRental");
+            let ctx = ReclaimRentalCtx::load(accounts)?;
+            instructions::rental::reclaim_rental::handler(ctx)
+        }
+        CreatorStandardInstruction::TransferByInUser(ix) => {
+            msg!("CreatorStandardInstruction::TransferByInUser");
+            let ctx = TransferByInUserCtx::load(accounts)?;
+            instructions::token::transfer_by_in_user::handler(ctx, ix)
+        }
+        CreatorStandardInstruction::MigrateRuleset => {
+            msg!("CreatorStandardInstruction::MigrateRuleset");
+            let ctx = MigrateRulesetCtx::load(accounts)?;
+            instructions::ruleset::migrate_ruleset::handler(ctx)
+        }
+        CreatorStandardInstruction::MigrateMintManager => {
+            msg!("CreatorStandardInstruction::MigrateMintManager");
+            let ctx = MigrateMintManagerCtx::load(accounts)?;
+            instructions::mint_manager::migrate_mint_manager::handler(ctx)
+        }
     }
 }
@@ -1,7 +1,41 @@
 use solana_program::pubkey::Pubkey;
 
+use crate::state::mpl_token_metadata;
+use crate::state::COLLECTION_MANAGER_SEED;
+use crate::state::IN_USE_RECORD_SEED;
 use crate::state::MINT_MANAGER_SEED;
+use crate::state::MINT_METADATA_SEED;
+use crate::state::RENTAL_SEED;
 
 pub fn find_mint_manager_address(mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[MINT_MANAGER_SEED.as_bytes(), mint.as_ref()], &crate::id())
 }
+
+pub fn find_mint_metadata_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MINT_METADATA_SEED.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            mint.as_ref(),
+        ],
+        &mpl_token_metadata::id(),
+    )
+}
+
+pub fn find_collection_manager_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COLLECTION_MANAGER_SEED.as_bytes(), collection.as_ref()],
+        &crate::id(),
+    )
+}
+
+pub fn find_in_use_record_address(holder_token_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[IN_USE_RECORD_SEED.as_bytes(), holder_token_account.as_ref()],
+        &crate::id(),
+    )
+}
+
+pub fn find_rental_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RENTAL_SEED.as_bytes(), mint.as_ref()], &crate::id())
+}
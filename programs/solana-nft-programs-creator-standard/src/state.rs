@@ -3,8 +3,11 @@ use borsh::BorshSerialize;
 use shank::ShankAccount;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::hash::hash;
+use solana_program::instruction::AccountMeta;
+use solana_program::instruction::Instruction;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::serialize_utils::read_u16;
 
 use crate::utils::assert_with_msg;
 
@@ -17,11 +20,22 @@ use std::slice::Iter;
 use std::u8;
 
 use borsh::maybestd::io::Error as BorshError;
+use lazy_format::lazy_format;
 use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::system_program;
+use solana_program::sysvar;
+use solana_program::sysvar::instructions::load_current_index_checked;
+use solana_program::sysvar::instructions::load_instruction_at_checked;
+use solana_program::sysvar::Sysvar;
 
 use crate::errors::ErrorCode;
 use crate::id;
+use crate::utils::assert_address;
+use crate::utils::assert_amount;
 use crate::utils::assert_owner;
+use crate::utils::assert_signer;
+use crate::utils::grow_account;
 
 ///////////// CONSTANTS /////////////
 pub const BASE_PROGRAMS: [&str; 2] = [
@@ -41,7 +55,10 @@ pub fn is_base_program(program_id: &Pubkey) -> bool {
 pub enum AccountType {
     Ruleset = 0,
     MintManager = 1,
-    Unrecognized = 2,
+    CollectionManager = 2,
+    InUseRecord = 3,
+    Rental = 4,
+    Unrecognized = 5,
 }
 
 impl From<u8> for AccountType {
@@ -49,6 +66,9 @@ impl From<u8> for AccountType {
         match orig {
             0 => AccountType::Ruleset,
             1 => AccountType::MintManager,
+            2 => AccountType::CollectionManager,
+            3 => AccountType::InUseRecord,
+            4 => AccountType::Rental,
             _ => AccountType::Unrecognized,
         }
     }
@@ -59,6 +79,9 @@ impl Display for AccountType {
         match self {
             AccountType::Ruleset => write!(f, "Ruleset"),
             AccountType::MintManager => write!(f, "MintManager"),
+            AccountType::CollectionManager => write!(f, "CollectionManager"),
+            AccountType::InUseRecord => write!(f, "InUseRecord"),
+            AccountType::Rental => write!(f, "Rental"),
             AccountType::Unrecognized => write!(f, "Unrecognized"),
         }
     }
@@ -131,11 +154,21 @@ pub fn assert_mint_manager_seeds(
 }
 
 pub const MINT_MANAGER_SEED: &str = "mint-manager";
+pub const MINT_MANAGER_VERSION: u8 = 1;
 pub const MINT_MANAGER_SIZE: usize = std::mem::size_of::<MintManager>() + 64;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, ShankAccount)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, BorshSerialize, BorshDeserialize)]
+pub enum MintManagerKind {
+    NonFungible = 0,
+    SemiFungible = 1,
+    Fungible = 2,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, BorshSerialize, PartialEq, Eq, Debug, ShankAccount)]
 pub struct MintManager {
     pub account_type: [u8; 8], // account discriminator
     pub version: u8,           // for potential future verisioning
@@ -143,6 +176,45 @@ pub struct MintManager {
     pub authority: Pubkey,
     pub ruleset: Pubkey,
     pub in_use_by: Option<Pubkey>,
+    // added in version 1
+    pub authority_renounced: bool,
+    pub collection_manager: Option<Pubkey>,
+    pub kind: MintManagerKind,
+    pub recall_disabled: bool,
+    pub suspended: bool,
+    pub in_use_until: Option<i64>,
+    pub additional_in_use_locks: Vec<InUseLock>,
+    pub in_use_release_requested_at: Option<i64>,
+    pub in_use_purpose: InUsePurpose,
+    pub in_use_reference: Option<Pubkey>,
+}
+
+// version 0 mint managers end after in_use_by, later fields take their defaults
+impl BorshDeserialize for MintManager {
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, BorshError> {
+        let mut mint_manager = MintManager {
+            account_type: BorshDeserialize::deserialize(buf)?,
+            version: BorshDeserialize::deserialize(buf)?,
+            mint: BorshDeserialize::deserialize(buf)?,
+            authority: BorshDeserialize::deserialize(buf)?,
+            ruleset: BorshDeserialize::deserialize(buf)?,
+            in_use_by: BorshDeserialize::deserialize(buf)?,
+            ..MintManager::new()
+        };
+        if mint_manager.version >= 1 {
+            mint_manager.authority_renounced = BorshDeserialize::deserialize(buf)?;
+            mint_manager.collection_manager = BorshDeserialize::deserialize(buf)?;
+            mint_manager.kind = BorshDeserialize::deserialize(buf)?;
+            mint_manager.recall_disabled = BorshDeserialize::deserialize(buf)?;
+            mint_manager.suspended = BorshDeserialize::deserialize(buf)?;
+            mint_manager.in_use_until = BorshDeserialize::deserialize(buf)?;
+            mint_manager.additional_in_use_locks = BorshDeserialize::deserialize(buf)?;
+            mint_manager.in_use_release_requested_at = BorshDeserialize::deserialize(buf)?;
+            mint_manager.in_use_purpose = BorshDeserialize::deserialize(buf)?;
+            mint_manager.in_use_reference = BorshDeserialize::deserialize(buf)?;
+        }
+        Ok(mint_manager)
+    }
 }
 
 impl CreatorStandardAccount for MintManager {
@@ -161,6 +233,16 @@ impl CreatorStandardAccount for MintManager {
             authority: Pubkey::default(),
             ruleset: Pubkey::default(),
             in_use_by: None,
+            authority_renounced: false,
+            collection_manager: None,
+            kind: MintManagerKind::NonFungible,
+            recall_disabled: false,
+            suspended: false,
+            in_use_until: None,
+            additional_in_use_locks: vec![],
+            in_use_release_requested_at: None,
+            in_use_purpose: InUsePurpose::Unspecified,
+            in_use_reference: None,
         }
     }
 
@@ -169,6 +251,10 @@ impl CreatorStandardAccount for MintManager {
     }
 
     fn save(&self, account: &AccountInfo) -> ProgramResult {
+        // fields added since version 0 are not read back from older accounts
+        if self.version < MINT_MANAGER_VERSION || self.try_to_vec()?.len() > account.data_len() {
+            return Err(ProgramError::from(ErrorCode::MigrationRequired));
+        }
         BorshSerialize::serialize(self, &mut *account.data.borrow_mut())?;
         Ok(())
     }
@@ -198,8 +284,643 @@ impl CreatorStandardAccount for MintManager {
 }
 ///////////// MINT MANAGER /////////////
 
+///////////// IN USE RECORD /////////////
+#[inline]
+pub fn in_use_record_seeds(holder_token_account: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+    let mut seeds = vec![
+        IN_USE_RECORD_SEED.as_bytes().to_vec(),
+        holder_token_account.as_ref().to_vec(),
+    ];
+    let (key, bump) = Pubkey::find_program_address(
+        &seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(),
+        &crate::id(),
+    );
+    seeds.push(vec![bump]);
+    (key, seeds)
+}
+
+#[inline]
+pub fn assert_in_use_record_seeds(
+    holder_token_account: &Pubkey,
+    expected_key: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let (key, seeds) = in_use_record_seeds(holder_token_account);
+    assert_with_msg(
+        expected_key == &key,
+        ProgramError::InvalidInstructionData,
+        "Invalid in use record seeds".to_string(),
+    )?;
+    Ok(seeds)
+}
+
+pub const IN_USE_RECORD_SEED: &str = "in-use-record";
+// including the primary in_use_by lock
+pub const MAX_IN_USE_LOCKS: usize = 4;
+pub const IN_USE_RECORD_SIZE: usize = std::mem::size_of::<InUseRecord>() + 64;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug)]
+pub struct InUseLock {
+    pub in_use_by: Pubkey,
+    pub in_use_until: Option<i64>,
+    pub release_requested_at: Option<i64>,
+    pub purpose: InUsePurpose,
+    pub reference: Option<Pubkey>,
+}
+
+// why a token is in use, surfaced to wallets alongside the optional reference account
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, BorshSerialize, BorshDeserialize)]
+pub enum InUsePurpose {
+    Unspecified = 0,
+    Staked = 1,
+    Listed = 2,
+    Rented = 3,
+    Collateral = 4,
+    InGame = 5,
+}
+
+// in use state of a single holder token account for semi-fungible and fungible mint managers
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, ShankAccount)]
+pub struct InUseRecord {
+    pub account_type: [u8; 8], // account discriminator
+    pub version: u8,           // for potential future verisioning
+    pub mint: Pubkey,
+    pub holder_token_account: Pubkey,
+    pub in_use_by: Option<Pubkey>,
+    pub in_use_until: Option<i64>,
+    pub additional_in_use_locks: Vec<InUseLock>,
+    pub in_use_release_requested_at: Option<i64>,
+    pub in_use_purpose: InUsePurpose,
+    pub in_use_reference: Option<Pubkey>,
+}
+
+impl CreatorStandardAccount for InUseRecord {
+    fn hash() -> [u8; 8] {
+        let discriminator_preimage = format!("account:{}", "InUseRecord");
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&hash(discriminator_preimage.as_bytes()).to_bytes()[..8]);
+        discriminator
+    }
+
+    fn new() -> Self {
+        InUseRecord {
+            account_type: InUseRecord::hash(),
+            version: 0,
+            mint: Pubkey::default(),
+            holder_token_account: Pubkey::default(),
+            in_use_by: None,
+            in_use_until: None,
+            additional_in_use_locks: vec![],
+            in_use_release_requested_at: None,
+            in_use_purpose: InUsePurpose::Unspecified,
+            in_use_reference: None,
+        }
+    }
+
+    fn account_type() -> AccountType {
+        AccountType::InUseRecord
+    }
+
+    fn save(&self, account: &AccountInfo) -> ProgramResult {
+        BorshSerialize::serialize(self, &mut *account.data.borrow_mut())?;
+        Ok(())
+    }
+}
+
+// non fungible mint managers track in use on the mint manager itself, all other kinds
+// pass the in use record of the holder token account as the next remaining account
+pub fn next_in_use_record<'a, 'info>(
+    mint_manager: &MintManager,
+    holder_token_account: &Pubkey,
+    remaining_accounts: &mut Vec<&'a AccountInfo<'info>>,
+) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+    if mint_manager.kind == MintManagerKind::NonFungible {
+        return Ok(None);
+    }
+    if remaining_accounts.is_empty() {
+        return Err(ProgramError::from(ErrorCode::NotEnoughRemainingAccounts));
+    }
+    let in_use_record_info = remaining_accounts.remove(0);
+    assert_in_use_record_seeds(holder_token_account, in_use_record_info.key)?;
+    Ok(Some(in_use_record_info))
+}
+
+// stored primary in_use_by lock regardless of expiration
+pub fn in_use_lock(
+    mint_manager: &MintManager,
+    in_use_record: Option<&AccountInfo>,
+) -> Result<Option<InUseLock>, ProgramError> {
+    let (in_use_by, in_use_until, release_requested_at, purpose, reference) = match in_use_record {
+        Some(in_use_record_info) if !in_use_record_info.data_is_empty() => {
+            let in_use_record: InUseRecord = InUseRecord::from_account_info(in_use_record_info)?;
+            (
+                in_use_record.in_use_by,
+                in_use_record.in_use_until,
+                in_use_record.in_use_release_requested_at,
+                in_use_record.in_use_purpose,
+                in_use_record.in_use_reference,
+            )
+        }
+        Some(_) => (None, None, None, InUsePurpose::Unspecified, None),
+        None => (
+            mint_manager.in_use_by,
+            mint_manager.in_use_until,
+            mint_manager.in_use_release_requested_at,
+            mint_manager.in_use_purpose,
+            mint_manager.in_use_reference,
+        ),
+    };
+    Ok(in_use_by.map(|in_use_by| InUseLock {
+        in_use_by,
+        in_use_until,
+        release_requested_at,
+        purpose,
+        reference,
+    }))
+}
+
+pub fn additional_in_use_locks(
+    mint_manager: &MintManager,
+    in_use_record: Option<&AccountInfo>,
+) -> Result<Vec<InUseLock>, ProgramError> {
+    match in_use_record {
+        Some(in_use_record_info) if !in_use_record_info.data_is_empty() => {
+            let in_use_record: InUseRecord = InUseRecord::from_account_info(in_use_record_info)?;
+            Ok(in_use_record.additional_in_use_locks)
+        }
+        Some(_) => Ok(vec![]),
+        None => Ok(mint_manager.additional_in_use_locks.clone()),
+    }
+}
+
+// every stored lock including the primary in_use_by, regardless of expiration
+pub fn in_use_locks(
+    mint_manager: &MintManager,
+    in_use_record: Option<&AccountInfo>,
+) -> Result<Vec<InUseLock>, ProgramError> {
+    let mut locks: Vec<InUseLock> = in_use_lock(mint_manager, in_use_record)?
+        .into_iter()
+        .collect();
+    locks.extend(additional_in_use_locks(mint_manager, in_use_record)?);
+    Ok(locks)
+}
+
+pub fn is_in_use_expired(in_use_until: Option<i64>) -> Result<bool, ProgramError> {
+    match in_use_until {
+        Some(in_use_until) => Ok(Clock::get()?.unix_timestamp >= in_use_until),
+        None => Ok(false),
+    }
+}
+
+pub fn assert_in_use_until(in_use_until: Option<i64>) -> ProgramResult {
+    if is_in_use_expired(in_use_until)? {
+        return Err(ProgramError::from(ErrorCode::InvalidInUseUntil));
+    }
+    Ok(())
+}
+
+// an expired lock is treated as released
+pub fn in_use_by(
+    mint_manager: &MintManager,
+    in_use_record: Option<&AccountInfo>,
+) -> Result<Option<Pubkey>, ProgramError> {
+    match in_use_lock(mint_manager, in_use_record)? {
+        Some(lock) if !is_in_use_expired(lock.in_use_until)? => Ok(Some(lock.in_use_by)),
+        _ => Ok(None),
+    }
+}
+
+pub fn is_token_in_use(
+    mint_manager: &MintManager,
+    in_use_record: Option<&AccountInfo>,
+) -> Result<bool, ProgramError> {
+    for lock in in_use_locks(mint_manager, in_use_record)? {
+        if !is_in_use_expired(lock.in_use_until)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// fungible and semi fungible holder token accounts need InitInUseRecord before any lock is written
+pub fn assert_in_use_record_initialized(in_use_record_info: &AccountInfo) -> ProgramResult {
+    if in_use_record_info.data_is_empty() {
+        return Err(ProgramError::from(ErrorCode::InUseRecordNotInitialized));
+    }
+    Ok(())
+}
+
+// in use records follow their holder token account when it is closed
+pub fn close_in_use_record(
+    in_use_record: Option<&AccountInfo>,
+    destination: &AccountInfo,
+) -> ProgramResult {
+    let in_use_record_info = match in_use_record {
+        Some(in_use_record_info) if !in_use_record_info.data_is_empty() => in_use_record_info,
+        _ => return Ok(()),
+    };
+    let destination_starting_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(in_use_record_info.lamports())
+        .expect("Add error");
+    **in_use_record_info.lamports.borrow_mut() = 0;
+
+    in_use_record_info.assign(&system_program::id());
+    in_use_record_info
+        .realloc(0, false)
+        .expect("Error reallocating account");
+    Ok(())
+}
+
+pub fn save_in_use_by(
+    mint_manager_info: &AccountInfo,
+    in_use_record: Option<&AccountInfo>,
+    in_use_by: Option<Pubkey>,
+    in_use_until: Option<i64>,
+    in_use_purpose: InUsePurpose,
+    in_use_reference: Option<Pubkey>,
+) -> ProgramResult {
+    match in_use_record {
+        Some(in_use_record_info) => {
+            assert_in_use_record_initialized(in_use_record_info)?;
+            let mut in_use_record: InUseRecord =
+                InUseRecord::from_account_info(in_use_record_info)?;
+            in_use_record.in_use_by = in_use_by;
+            in_use_record.in_use_until = in_use_until;
+            in_use_record.in_use_release_requested_at = None;
+            in_use_record.in_use_purpose = in_use_purpose;
+            in_use_record.in_use_reference = in_use_reference;
+            in_use_record.save(in_use_record_info)
+        }
+        None => {
+            let mut mint_manager: MintManager = MintManager::from_account_info(mint_manager_info)?;
+            mint_manager.in_use_by = in_use_by;
+            mint_manager.in_use_until = in_use_until;
+            mint_manager.in_use_release_requested_at = None;
+            mint_manager.in_use_purpose = in_use_purpose;
+            mint_manager.in_use_reference = in_use_reference;
+            mint_manager.save(mint_manager_info)
+        }
+    }
+}
+
+pub fn save_additional_in_use_locks(
+    mint_manager_info: &AccountInfo,
+    in_use_record: Option<&AccountInfo>,
+    additional_in_use_locks: Vec<InUseLock>,
+) -> ProgramResult {
+    match in_use_record {
+        Some(in_use_record_info) => {
+            assert_in_use_record_initialized(in_use_record_info)?;
+            let mut in_use_record: InUseRecord =
+                InUseRecord::from_account_info(in_use_record_info)?;
+            in_use_record.additional_in_use_locks = additional_in_use_locks;
+            in_use_record.save(in_use_record_info)
+        }
+        None => {
+            let mut mint_manager: MintManager = MintManager::from_account_info(mint_manager_info)?;
+            mint_manager.additional_in_use_locks = additional_in_use_locks;
+            mint_manager.save(mint_manager_info)
+        }
+    }
+}
+
+// appends a lock alongside the primary in_use_by, dropping expired ones and
+// reallocating the account holding the locks when needed
+pub fn push_additional_in_use_lock<'info>(
+    mint_manager_info: &AccountInfo<'info>,
+    in_use_record: Option<&AccountInfo<'info>>,
+    in_use_lock: InUseLock,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    let mut mint_manager: MintManager = MintManager::from_account_info(mint_manager_info)?;
+    let mut locks = vec![];
+    for lock in additional_in_use_locks(&mint_manager, in_use_record)? {
+        if !is_in_use_expired(lock.in_use_until)? {
+            locks.push(lock);
+        }
+    }
+    locks.push(in_use_lock);
+    // the primary in_use_by counts towards the limit
+    if locks.len() + 1 > MAX_IN_USE_LOCKS {
+        return Err(ProgramError::from(ErrorCode::TooManyInUseLocks));
+    }
+
+    match in_use_record {
+        Some(in_use_record_info) => {
+            assert_in_use_record_initialized(in_use_record_info)?;
+            let mut in_use_record: InUseRecord =
+                InUseRecord::from_account_info(in_use_record_info)?;
+            in_use_record.additional_in_use_locks = locks;
+            grow_account(
+                in_use_record_info,
+                in_use_record.try_to_vec()?.len(),
+                payer,
+                system_program,
+            )?;
+            in_use_record.save(in_use_record_info)
+        }
+        None => {
+            mint_manager.additional_in_use_locks = locks;
+            grow_account(
+                mint_manager_info,
+                mint_manager.try_to_vec()?.len(),
+                payer,
+                system_program,
+            )?;
+            mint_manager.save(mint_manager_info)
+        }
+    }
+}
+
+// clears a single lock, every other lock stays in place
+pub fn remove_in_use_lock(
+    mint_manager_info: &AccountInfo,
+    in_use_record: Option<&AccountInfo>,
+    in_use_by: &Pubkey,
+) -> ProgramResult {
+    let mint_manager: MintManager = MintManager::from_account_info(mint_manager_info)?;
+    if in_use_lock(&mint_manager, in_use_record)?.map(|lock| lock.in_use_by) == Some(*in_use_by) {
+        return save_in_use_by(
+            mint_manager_info,
+            in_use_record,
+            None,
+            None,
+            InUsePurpose::Unspecified,
+            None,
+        );
+    }
+    let additional_in_use_locks = additional_in_use_locks(&mint_manager, in_use_record)?
+        .into_iter()
+        .filter(|lock| &lock.in_use_by != in_use_by)
+        .collect();
+    save_additional_in_use_locks(mint_manager_info, in_use_record, additional_in_use_locks)
+}
+
+pub fn save_in_use_release_requested_at(
+    mint_manager_info: &AccountInfo,
+    in_use_record: Option<&AccountInfo>,
+    in_use_by: &Pubkey,
+    release_requested_at: Option<i64>,
+) -> ProgramResult {
+    let mut mint_manager: MintManager = MintManager::from_account_info(mint_manager_info)?;
+    match in_use_record {
+        Some(in_use_record_info) => {
+            assert_in_use_record_initialized(in_use_record_info)?;
+            let mut in_use_record: InUseRecord =
+                InUseRecord::from_account_info(in_use_record_info)?;
+            if in_use_record.in_use_by == Some(*in_use_by) {
+                in_use_record.in_use_release_requested_at = release_requested_at;
+            }
+            for lock in in_use_record.additional_in_use_locks.iter_mut() {
+                if &lock.in_use_by == in_use_by {
+                    lock.release_requested_at = release_requested_at;
+                }
+            }
+            in_use_record.save(in_use_record_info)
+        }
+        None => {
+            if mint_manager.in_use_by == Some(*in_use_by) {
+                mint_manager.in_use_release_requested_at = release_requested_at;
+            }
+            for lock in mint_manager.additional_in_use_locks.iter_mut() {
+                if &lock.in_use_by == in_use_by {
+                    lock.release_requested_at = release_requested_at;
+                }
+            }
+            mint_manager.save(mint_manager_info)
+        }
+    }
+}
+
+pub fn assert_holder_token_amount(
+    mint_manager: &MintManager,
+    amount: u64,
+    name: &str,
+) -> ProgramResult {
+    if mint_manager.kind == MintManagerKind::NonFungible {
+        return assert_amount(&amount.to_string(), "1", name);
+    }
+    assert_with_msg(
+        amount > 0,
+        ProgramError::from(ErrorCode::InvalidAmount),
+        lazy_format!("{} amount must be greater than 0", name),
+    )
+}
+///////////// IN USE RECORD /////////////
+
+///////////// RENTAL /////////////
+#[inline]
+pub fn rental_seeds(mint_id: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+    let mut seeds = vec![RENTAL_SEED.as_bytes().to_vec(), mint_id.as_ref().to_vec()];
+    let (key, bump) = Pubkey::find_program_address(
+        &seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(),
+        &crate::id(),
+    );
+    seeds.push(vec![bump]);
+    (key, seeds)
+}
+
+#[inline]
+pub fn assert_rental_seeds(
+    mint_id: &Pubkey,
+    expected_key: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let (key, seeds) = rental_seeds(mint_id);
+    assert_with_msg(
+        expected_key == &key,
+        ProgramError::InvalidInstructionData,
+        "Invalid rental seeds".to_string(),
+    )?;
+    Ok(seeds)
+}
+
+pub const RENTAL_SEED: &str = "rental";
+pub const RENTAL_SIZE: usize = std::mem::size_of::<Rental>() + 64;
+
+// rental terms listed by the owner, the listed token stays in use by the rental
+// until it is cancelled or reclaimed
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, ShankAccount)]
+pub struct Rental {
+    pub account_type: [u8; 8], // account discriminator
+    pub version: u8,           // for potential future verisioning
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub owner_token_account: Pubkey,
+    pub price: u64,
+    pub duration_seconds: i64,
+    pub renter: Option<Pubkey>,
+    pub renter_token_account: Option<Pubkey>,
+    pub expiration: Option<i64>,
+}
+
+impl CreatorStandardAccount for Rental {
+    fn hash() -> [u8; 8] {
+        let discriminator_preimage = format!("account:{}", "Rental");
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&hash(discriminator_preimage.as_bytes()).to_bytes()[..8]);
+        discriminator
+    }
+
+    fn new() -> Self {
+        Rental {
+            account_type: Rental::hash(),
+            version: 0,
+            mint: Pubkey::default(),
+            owner: Pubkey::default(),
+            owner_token_account: Pubkey::default(),
+            price: 0,
+            duration_seconds: 0,
+            renter: None,
+            renter_token_account: None,
+            expiration: None,
+        }
+    }
+
+    fn account_type() -> AccountType {
+        AccountType::Rental
+    }
+
+    fn save(&self, account: &AccountInfo) -> ProgramResult {
+        BorshSerialize::serialize(self, &mut *account.data.borrow_mut())?;
+        Ok(())
+    }
+}
+///////////// RENTAL /////////////
+
+///////////// COLLECTION MANAGER /////////////
+#[inline]
+pub fn collection_manager_seeds(collection: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+    let mut seeds = vec![
+        COLLECTION_MANAGER_SEED.as_bytes().to_vec(),
+        collection.as_ref().to_vec(),
+    ];
+    let (key, bump) = Pubkey::find_program_address(
+        &seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(),
+        &crate::id(),
+    );
+    seeds.push(vec![bump]);
+    (key, seeds)
+}
+
+#[inline]
+pub fn assert_collection_manager_seeds(
+    collection: &Pubkey,
+    expected_key: &Pubkey,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let (key, seeds) = collection_manager_seeds(collection);
+    assert_with_msg(
+        expected_key == &key,
+        ProgramError::InvalidInstructionData,
+        "Invalid collection manager seeds".to_string(),
+    )?;
+    Ok(seeds)
+}
+
+pub const COLLECTION_MANAGER_SEED: &str = "collection-manager";
+pub const COLLECTION_MANAGER_SIZE: usize = std::mem::size_of::<CollectionManager>() + 64;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, ShankAccount)]
+pub struct CollectionManager {
+    pub account_type: [u8; 8], // account discriminator
+    pub version: u8,           // for potential future verisioning
+    pub collection: Pubkey,
+    pub authority: Pubkey,
+    pub ruleset: Pubkey,
+}
+
+impl CreatorStandardAccount for CollectionManager {
+    fn hash() -> [u8; 8] {
+        let discriminator_preimage = format!("account:{}", "CollectionManager");
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&hash(discriminator_preimage.as_bytes()).to_bytes()[..8]);
+        discriminator
+    }
+
+    fn new() -> Self {
+        CollectionManager {
+            account_type: CollectionManager::hash(),
+            version: 0,
+            collection: Pubkey::default(),
+            authority: Pubkey::default(),
+            ruleset: Pubkey::default(),
+        }
+    }
+
+    fn account_type() -> AccountType {
+        AccountType::CollectionManager
+    }
+
+    fn save(&self, account: &AccountInfo) -> ProgramResult {
+        BorshSerialize::serialize(self, &mut *account.data.borrow_mut())?;
+        Ok(())
+    }
+}
+
+pub fn is_collection_manager_account(account: &AccountInfo) -> bool {
+    account.owner == &id()
+        && account.data_len() >= 8
+        && is_correct_account_type(&account.data.borrow(), CollectionManager::hash())
+}
+
+// mint managers that belong to a collection pass the collection manager as the first remaining account
+pub fn next_collection_manager<'a, 'info>(
+    mint_manager: &MintManager,
+    remaining_accounts: &mut Vec<&'a AccountInfo<'info>>,
+) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+    let collection_manager_id = match mint_manager.collection_manager {
+        Some(collection_manager_id) => collection_manager_id,
+        None => return Ok(None),
+    };
+    if remaining_accounts.is_empty() {
+        return Err(ProgramError::from(ErrorCode::InvalidCollectionManager));
+    }
+    let collection_manager_info = remaining_accounts.remove(0);
+    assert_address(
+        collection_manager_info.key,
+        &collection_manager_id,
+        "collection_manager",
+    )?;
+    Ok(Some(collection_manager_info))
+}
+
+// the effective ruleset of a mint manager is the collection ruleset when it belongs to a collection
+pub fn assert_mint_manager_ruleset(
+    mint_manager: &MintManager,
+    ruleset: &AccountInfo,
+    collection_manager: Option<&AccountInfo>,
+) -> ProgramResult {
+    match mint_manager.collection_manager {
+        Some(collection_manager_id) => {
+            let collection_manager_info =
+                collection_manager.ok_or(ErrorCode::InvalidCollectionManager)?;
+            assert_address(
+                collection_manager_info.key,
+                &collection_manager_id,
+                "collection_manager",
+            )?;
+            let collection_manager: CollectionManager =
+                CollectionManager::from_account_info(collection_manager_info)?;
+            assert_address(&collection_manager.ruleset, ruleset.key, "ruleset")
+        }
+        None => assert_address(&mint_manager.ruleset, ruleset.key, "ruleset"),
+    }
+}
+///////////// COLLECTION MANAGER /////////////
+
 ///////////// RULESET /////////////
 pub const RULESET_SEED: &str = "ruleset";
+pub const RULESET_VERSION: u8 = 1;
 #[inline]
 pub fn ruleset_seeds(name: &String) -> (Pubkey, Vec<Vec<u8>>) {
     let mut seeds = vec![RULESET_SEED.as_bytes().to_vec(), name.as_bytes().to_vec()];
@@ -226,21 +947,78 @@ pub fn assert_ruleset_seeds(
 }
 
 #[inline]
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_ruleset_size(
+    name: &String,
     allowed_programs: &Vec<Pubkey>,
     disallowed_addresses: &Vec<Pubkey>,
     extensions: &Vec<Pubkey>,
+    approved_creators: &Vec<Pubkey>,
+    uri: &String,
+    required_creators: &Vec<Pubkey>,
+    payment_mints: &Vec<Pubkey>,
+    pre_transfer_instructions: &Vec<RequiredInstruction>,
+    post_transfer_instructions: &Vec<RequiredInstruction>,
 ) -> usize {
     std::mem::size_of::<Ruleset>()
-        + allowed_programs.len()
-        + disallowed_addresses.len()
-        + extensions.len()
+        + 4
+        + name.len()
+        + 4
+        + uri.len()
+        + 4
+        + 32 * allowed_programs.len()
+        + 4
+        + 32 * disallowed_addresses.len()
+        + 4
+        + 32 * extensions.len()
+        + 4
+        + 32 * approved_creators.len()
+        + 4
+        + 32 * required_creators.len()
+        + 4
+        + 32 * payment_mints.len()
+        + pre_transfer_instructions
+            .iter()
+            .map(|ix| ix.size())
+            .sum::<usize>()
+        + post_transfer_instructions
+            .iter()
+            .map(|ix| ix.size())
+            .sum::<usize>()
         + 64
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankAccount)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, BorshSerialize, BorshDeserialize)]
+pub enum RulesetCategory {
+    Unspecified = 0,
+    RoyaltyEnforcing = 1,
+    Soulbound = 2,
+    Open = 3,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RequiredInstruction {
+    pub program_id: Pubkey,
+    pub discriminator: Vec<u8>,
+}
+
+impl RequiredInstruction {
+    pub fn size(&self) -> usize {
+        32 + 4 + self.discriminator.len()
+    }
+
+    pub fn matches(&self, ix: &Instruction) -> bool {
+        ix.program_id == self.program_id && ix.data.starts_with(&self.discriminator)
+    }
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, PartialEq, Eq, Debug, Clone, ShankAccount)]
 pub struct Ruleset {
     pub account_type: [u8; 8], // account discriminator
     pub version: u8,           // for potential future verisioning
@@ -249,6 +1027,58 @@ pub struct Ruleset {
     pub allowed_programs: Vec<Pubkey>,
     pub disallowed_addresses: Vec<Pubkey>,
     pub extensions: Vec<Pubkey>,
+    // added in version 1
+    pub locked: bool,
+    pub require_attachment_approval: bool,
+    pub approved_creators: Vec<Pubkey>,
+    pub uri: String,
+    pub description_hash: [u8; 32],
+    pub category: RulesetCategory,
+    pub minimum_creator_basis_points: u16,
+    pub required_creators: Vec<Pubkey>,
+    pub require_royalty_payment: bool,
+    pub payment_mints: Vec<Pubkey>,
+    pub pre_transfer_instructions: Vec<RequiredInstruction>,
+    pub post_transfer_instructions: Vec<RequiredInstruction>,
+    pub allow_recall: bool,
+    pub disallow_suspension: bool,
+    pub in_use_release_grace_period_seconds: Option<i64>,
+    pub allow_authority_remove_in_use_by: bool,
+}
+
+// version 0 rulesets end after extensions, later fields take their defaults
+impl BorshDeserialize for Ruleset {
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, BorshError> {
+        let mut ruleset = Ruleset {
+            account_type: BorshDeserialize::deserialize(buf)?,
+            version: BorshDeserialize::deserialize(buf)?,
+            authority: BorshDeserialize::deserialize(buf)?,
+            name: BorshDeserialize::deserialize(buf)?,
+            allowed_programs: BorshDeserialize::deserialize(buf)?,
+            disallowed_addresses: BorshDeserialize::deserialize(buf)?,
+            extensions: BorshDeserialize::deserialize(buf)?,
+            ..Ruleset::new()
+        };
+        if ruleset.version >= 1 {
+            ruleset.locked = BorshDeserialize::deserialize(buf)?;
+            ruleset.require_attachment_approval = BorshDeserialize::deserialize(buf)?;
+            ruleset.approved_creators = BorshDeserialize::deserialize(buf)?;
+            ruleset.uri = BorshDeserialize::deserialize(buf)?;
+            ruleset.description_hash = BorshDeserialize::deserialize(buf)?;
+            ruleset.category = BorshDeserialize::deserialize(buf)?;
+            ruleset.minimum_creator_basis_points = BorshDeserialize::deserialize(buf)?;
+            ruleset.required_creators = BorshDeserialize::deserialize(buf)?;
+            ruleset.require_royalty_payment = BorshDeserialize::deserialize(buf)?;
+            ruleset.payment_mints = BorshDeserialize::deserialize(buf)?;
+            ruleset.pre_transfer_instructions = BorshDeserialize::deserialize(buf)?;
+            ruleset.post_transfer_instructions = BorshDeserialize::deserialize(buf)?;
+            ruleset.allow_recall = BorshDeserialize::deserialize(buf)?;
+            ruleset.disallow_suspension = BorshDeserialize::deserialize(buf)?;
+            ruleset.in_use_release_grace_period_seconds = BorshDeserialize::deserialize(buf)?;
+            ruleset.allow_authority_remove_in_use_by = BorshDeserialize::deserialize(buf)?;
+        }
+        Ok(ruleset)
+    }
 }
 
 impl CreatorStandardAccount for Ruleset {
@@ -268,6 +1098,22 @@ impl CreatorStandardAccount for Ruleset {
             allowed_programs: Vec::new(),
             disallowed_addresses: Vec::new(),
             extensions: Vec::new(),
+            locked: false,
+            require_attachment_approval: false,
+            approved_creators: Vec::new(),
+            uri: String::from(""),
+            description_hash: [0u8; 32],
+            category: RulesetCategory::Unspecified,
+            minimum_creator_basis_points: 0,
+            required_creators: Vec::new(),
+            require_royalty_payment: false,
+            payment_mints: Vec::new(),
+            pre_transfer_instructions: Vec::new(),
+            post_transfer_instructions: Vec::new(),
+            allow_recall: false,
+            disallow_suspension: false,
+            in_use_release_grace_period_seconds: None,
+            allow_authority_remove_in_use_by: false,
         }
     }
 
@@ -276,6 +1122,10 @@ impl CreatorStandardAccount for Ruleset {
     }
 
     fn save(&self, account: &AccountInfo) -> ProgramResult {
+        // fields added since version 0 are not read back from older rulesets
+        if self.version < RULESET_VERSION || self.try_to_vec()?.len() > account.data_len() {
+            return Err(ProgramError::from(ErrorCode::MigrationRequired));
+        }
         BorshSerialize::serialize(self, &mut *account.data.borrow_mut())?;
         Ok(())
     }
@@ -283,7 +1133,200 @@ impl CreatorStandardAccount for Ruleset {
 
 ///////////// RULESET /////////////
 
+///////////// MINT METADATA /////////////
+pub mod mpl_token_metadata {
+    solana_program::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+pub const MINT_METADATA_SEED: &str = "metadata";
+pub const MINT_METADATA_KEY_V1: u8 = 4;
+
+#[inline]
+pub fn mint_metadata_seeds(mint_id: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+    let mut seeds = vec![
+        MINT_METADATA_SEED.as_bytes().to_vec(),
+        mpl_token_metadata::id().as_ref().to_vec(),
+        mint_id.as_ref().to_vec(),
+    ];
+    let (key, bump) = Pubkey::find_program_address(
+        &seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(),
+        &mpl_token_metadata::id(),
+    );
+    seeds.push(vec![bump]);
+    (key, seeds)
+}
+
+#[inline]
+pub fn assert_mint_metadata_seeds(mint_id: &Pubkey, expected_key: &Pubkey) -> ProgramResult {
+    let (key, _) = mint_metadata_seeds(mint_id);
+    assert_with_msg(
+        expected_key == &key,
+        ProgramError::from(ErrorCode::InvalidMintMetadata),
+        "Invalid mint metadata seeds".to_string(),
+    )
+}
+
+// only the leading fields of the token metadata account that this program reads
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MintMetadataCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MintMetadataData {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<MintMetadataCreator>>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MintMetadataCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MintMetadata {
+    pub key: u8,
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub data: MintMetadataData,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>,
+    pub collection: Option<MintMetadataCollection>,
+}
+
+impl MintMetadata {
+    pub fn from_account_info(account: &AccountInfo) -> Result<MintMetadata, ProgramError> {
+        assert_owner(account, &mpl_token_metadata::id(), "mint_metadata")?;
+        let data = account.data.borrow();
+        if data.is_empty() || data[0] != MINT_METADATA_KEY_V1 {
+            return Err(ProgramError::from(ErrorCode::InvalidMintMetadata));
+        }
+        let metadata: MintMetadata = MintMetadata::deserialize(&mut &data[..])
+            .map_err(|_| ErrorCode::InvalidMintMetadata)?;
+        Ok(metadata)
+    }
+
+    pub fn is_update_authority_or_verified_creator(&self, address: &Pubkey) -> bool {
+        if &self.update_authority == address {
+            return true;
+        }
+        match &self.data.creators {
+            Some(creators) => creators
+                .iter()
+                .any(|creator| creator.verified && &creator.address == address),
+            None => false,
+        }
+    }
+
+    pub fn is_verified_collection_member(&self, collection: &Pubkey) -> bool {
+        match &self.collection {
+            Some(metadata_collection) => {
+                metadata_collection.verified && &metadata_collection.key == collection
+            }
+            None => false,
+        }
+    }
+}
+
+pub const CREATE_METADATA_ACCOUNT_V3_INSTRUCTION: u8 = 33;
+
+// CreateMetadataAccountV3 args, DataV2 is laid out as MintMetadataData followed by collection and uses
+#[derive(BorshSerialize)]
+struct CreateMintMetadataArgs {
+    instruction: u8,
+    data: MintMetadataData,
+    collection: Option<MintMetadataCollection>,
+    uses: Option<u8>,
+    is_mutable: bool,
+    collection_details: Option<u8>,
+}
+
+pub fn create_mint_metadata_instruction(
+    mint_metadata: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    data: MintMetadataData,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id: mpl_token_metadata::id(),
+        accounts: vec![
+            AccountMeta::new(*mint_metadata, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*mint_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: CreateMintMetadataArgs {
+            instruction: CREATE_METADATA_ACCOUNT_V3_INSTRUCTION,
+            data,
+            collection: None,
+            uses: None,
+            is_mutable: true,
+            collection_details: None,
+        }
+        .try_to_vec()?,
+    })
+}
+///////////// MINT METADATA /////////////
+
 ///////////// UTILS /////////////
+pub fn assert_ruleset_attachment_approval<'info>(
+    ruleset: &Ruleset,
+    remaining_accounts: &mut Iter<&AccountInfo<'info>>,
+) -> ProgramResult {
+    if !ruleset.require_attachment_approval {
+        return Ok(());
+    }
+
+    // curated rulesets need either the ruleset authority or an approved creator to sign
+    let approver_info = remaining_accounts
+        .next()
+        .ok_or(ErrorCode::NotEnoughRemainingAccounts)?;
+    assert_signer(approver_info, "attachment approver")?;
+    if approver_info.key != &ruleset.authority
+        && !ruleset.approved_creators.contains(approver_info.key)
+    {
+        return Err(ProgramError::from(ErrorCode::RulesetAttachmentNotApproved));
+    }
+    Ok(())
+}
+
+pub fn assert_mint_metadata_creator_share(
+    ruleset: &Ruleset,
+    mint_metadata: &MintMetadata,
+) -> ProgramResult {
+    if mint_metadata.data.seller_fee_basis_points < ruleset.minimum_creator_basis_points {
+        return Err(ProgramError::from(
+            ErrorCode::InusufficientMinimumCreatorShare,
+        ));
+    }
+
+    for required_creator in &ruleset.required_creators {
+        let is_verified_creator = match &mint_metadata.data.creators {
+            Some(creators) => creators
+                .iter()
+                .any(|creator| creator.verified && &creator.address == required_creator),
+            None => false,
+        };
+        if !is_verified_creator {
+            return Err(ProgramError::from(ErrorCode::RequiredCreatorNotVerified));
+        }
+    }
+    Ok(())
+}
+
 pub fn allowlist_disallowlist<'info>(
     ruleset: &Ruleset,
     remaining_accounts: &mut Iter<&AccountInfo<'info>>,
@@ -320,6 +1363,89 @@ pub fn allowlist_disallowlist<'info>(
     return Ok([allowed_programs, disallowed_addresses]);
 }
 
+// every instruction moving a managed token runs the ruleset program and pre / post instruction checks
+pub fn assert_transfer_instructions<'info>(
+    ruleset: &Ruleset,
+    instructions: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<&AccountInfo<'info>>,
+) -> ProgramResult {
+    // instruction_sysvar
+    let instruction_sysvar = instructions.try_borrow_data()?;
+
+    let mut current: usize = 0;
+    let num_instructions =
+        read_u16(&mut current, &instruction_sysvar).expect("Invalid instruction");
+
+    let [allowed_programs, disallowed_addresses] =
+        allowlist_disallowlist(ruleset, remaining_accounts)?;
+
+    let current_index = load_current_index_checked(instructions)?;
+    let mut missing_pre_transfer_instructions = ruleset.pre_transfer_instructions.clone();
+    let mut missing_post_transfer_instructions = ruleset.post_transfer_instructions.clone();
+
+    for i in 0..num_instructions {
+        let ix =
+            load_instruction_at_checked(i.into(), instructions).expect("Failed to get instruction");
+
+        if i < current_index {
+            missing_pre_transfer_instructions.retain(|required_ix| !required_ix.matches(&ix));
+        } else if i > current_index {
+            missing_post_transfer_instructions.retain(|required_ix| !required_ix.matches(&ix));
+        }
+
+        if !allowed_programs.is_empty()
+            && !is_base_program(&ix.program_id)
+            && !allowed_programs.contains(&ix.program_id.to_string())
+        {
+            return Err(ProgramError::from(ErrorCode::ProgramNotAllowed));
+        }
+
+        for account in ix.accounts {
+            if !disallowed_addresses.is_empty()
+                && (disallowed_addresses.contains(&ix.program_id.to_string())
+                    || disallowed_addresses.contains(&account.pubkey.to_string()))
+            {
+                return Err(ProgramError::from(ErrorCode::AddressDisallowed));
+            }
+        }
+    }
+
+    if !missing_pre_transfer_instructions.is_empty() {
+        return Err(ProgramError::from(ErrorCode::InvalidPreTransferInstruction));
+    }
+
+    if !missing_post_transfer_instructions.is_empty() {
+        return Err(ProgramError::from(
+            ErrorCode::InvalidPostTransferInstruction,
+        ));
+    }
+    Ok(())
+}
+
+// programs taking a token into use go through the same allow / disallow lists as transfers
+pub fn assert_in_use_by_allowed<'info>(
+    ruleset: &Ruleset,
+    in_use_by_address: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<&AccountInfo<'info>>,
+) -> ProgramResult {
+    let [allowed_programs, disallowed_addresses] =
+        allowlist_disallowlist(ruleset, remaining_accounts)?;
+    if !allowed_programs.is_empty()
+        && !is_base_program(in_use_by_address.owner)
+        && !allowed_programs.contains(&in_use_by_address.owner.to_string())
+    {
+        return Err(ProgramError::from(ErrorCode::ProgramNotAllowed));
+    }
+
+    if !disallowed_addresses.is_empty()
+        && (disallowed_addresses.contains(&in_use_by_address.owner.to_string())
+            || disallowed_addresses.contains(&in_use_by_address.key.to_string()))
+    {
+        return Err(ProgramError::from(ErrorCode::AddressDisallowed));
+    }
+    Ok(())
+}
+
 pub fn check_allowlist_disallowlist<'info>(
     account_id: &Pubkey,
     ruleset: &Ruleset,
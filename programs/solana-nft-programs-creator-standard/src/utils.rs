@@ -4,11 +4,17 @@ use lazy_format::lazy_format;
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
+use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
-use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
-use spl_token::state::Account as TokenAccount;
-use spl_token::state::Mint;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::transfer;
+use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use spl_token_2022::extension::BaseStateWithExtensions;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Account as TokenAccount;
+use spl_token_2022::state::Mint;
 
 use crate::errors::ErrorCode;
 use crate::id;
@@ -89,14 +95,31 @@ pub fn assert_program_account(account: &AccountInfo, discriminator: [u8; 8]) ->
     )
 }
 
+#[inline(always)]
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == spl_token_2022::id()
+}
+
+#[inline(always)]
+pub fn assert_token_program(token_program: &AccountInfo, mint: &AccountInfo) -> ProgramResult {
+    assert_with_msg(
+        is_token_program(token_program.key) && token_program.key == mint.owner,
+        ProgramError::IncorrectProgramId,
+        lazy_format!("token_program must equal mint owner {}", mint.owner),
+    )
+}
+
 #[inline(always)]
 pub fn unpack_checked_mint_account(
     account: &AccountInfo,
     name: Option<&str>,
 ) -> Result<Mint, ProgramError> {
-    let check_mint = Mint::unpack(&account.data.try_borrow().expect("Could not borrow data"));
+    let check_mint = StateWithExtensions::<Mint>::unpack(
+        &account.data.try_borrow().expect("Could not borrow data"),
+    )
+    .map(|state| state.base);
     assert_with_msg(
-        check_mint.is_ok() && *account.owner == spl_token::id(),
+        check_mint.is_ok() && is_token_program(account.owner),
         ProgramError::from(ErrorCode::InvalidMint),
         lazy_format!(
             "Invalid {} mint account {}",
@@ -112,10 +135,12 @@ pub fn unpack_checked_token_account(
     account: &AccountInfo,
     name: Option<&str>,
 ) -> Result<TokenAccount, ProgramError> {
-    let check_token_account =
-        TokenAccount::unpack(&account.data.try_borrow().expect("Could not borrow data"));
+    let check_token_account = StateWithExtensions::<TokenAccount>::unpack(
+        &account.data.try_borrow().expect("Could not borrow data"),
+    )
+    .map(|state| state.base);
     assert_with_msg(
-        check_token_account.is_ok() && *account.owner == spl_token::id(),
+        check_token_account.is_ok() && is_token_program(account.owner),
         ProgramError::from(ErrorCode::InvalidTokenAccount),
         lazy_format!(
             "Invalid {} token account {}",
@@ -125,3 +150,56 @@ pub fn unpack_checked_token_account(
     )?;
     check_token_account
 }
+
+#[inline(always)]
+pub fn unpack_mint_permanent_delegate(
+    account: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let data = account.data.try_borrow().expect("Could not borrow data");
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint
+        .get_extension::<PermanentDelegate>()
+        .ok()
+        .and_then(|extension| Option::<Pubkey>::from(extension.delegate)))
+}
+
+#[inline(always)]
+pub fn calculate_royalty_amount(price: u64, basis_points: u16) -> Result<u64, ProgramError> {
+    let royalty = (price as u128)
+        .checked_mul(basis_points as u128)
+        .and_then(|amount| amount.checked_div(10_000))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    u64::try_from(royalty).map_err(|_| ProgramError::ArithmeticOverflow)
+}
+
+#[inline(always)]
+pub fn calculate_creator_amount(royalty: u64, share: u8) -> Result<u64, ProgramError> {
+    royalty
+        .checked_mul(share as u64)
+        .and_then(|amount| amount.checked_div(100))
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+// grows an account to at least `new_len` bytes, topping up rent exemption from the payer
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    if new_len <= account.data_len() {
+        return Ok(());
+    }
+    let new_minimum_balance = Rent::get()?.minimum_balance(new_len);
+    if new_minimum_balance > account.lamports() {
+        invoke(
+            &transfer(
+                payer.key,
+                account.key,
+                new_minimum_balance.saturating_sub(account.lamports()),
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    account.realloc(new_len, false)
+}
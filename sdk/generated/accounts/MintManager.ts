@@ -8,6 +8,12 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { InUseLock, inUseLockBeet } from '../types/InUseLock'
+import { InUsePurpose, inUsePurposeBeet } from '../types/InUsePurpose'
+import {
+  MintManagerKind,
+  mintManagerKindBeet,
+} from '../types/MintManagerKind'
 
 /**
  * Arguments used to create {@link MintManager}
@@ -21,6 +27,16 @@ export type MintManagerArgs = {
   authority: web3.PublicKey
   ruleset: web3.PublicKey
   inUseBy: beet.COption<web3.PublicKey>
+  authorityRenounced: boolean
+  collectionManager: beet.COption<web3.PublicKey>
+  kind: MintManagerKind
+  recallDisabled: boolean
+  suspended: boolean
+  inUseUntil: beet.COption<beet.bignum>
+  additionalInUseLocks: InUseLock[]
+  inUseReleaseRequestedAt: beet.COption<beet.bignum>
+  inUsePurpose: InUsePurpose
+  inUseReference: beet.COption<web3.PublicKey>
 }
 /**
  * Holds the data for the {@link MintManager} Account and provides de/serialization
@@ -36,7 +52,17 @@ export class MintManager implements MintManagerArgs {
     readonly mint: web3.PublicKey,
     readonly authority: web3.PublicKey,
     readonly ruleset: web3.PublicKey,
-    readonly inUseBy: beet.COption<web3.PublicKey>
+    readonly inUseBy: beet.COption<web3.PublicKey>,
+    readonly authorityRenounced: boolean,
+    readonly collectionManager: beet.COption<web3.PublicKey>,
+    readonly kind: MintManagerKind,
+    readonly recallDisabled: boolean,
+    readonly suspended: boolean,
+    readonly inUseUntil: beet.COption<beet.bignum>,
+    readonly additionalInUseLocks: InUseLock[],
+    readonly inUseReleaseRequestedAt: beet.COption<beet.bignum>,
+    readonly inUsePurpose: InUsePurpose,
+    readonly inUseReference: beet.COption<web3.PublicKey>
   ) {}
 
   /**
@@ -49,7 +75,17 @@ export class MintManager implements MintManagerArgs {
       args.mint,
       args.authority,
       args.ruleset,
-      args.inUseBy
+      args.inUseBy,
+      args.authorityRenounced,
+      args.collectionManager,
+      args.kind,
+      args.recallDisabled,
+      args.suspended,
+      args.inUseUntil,
+      args.additionalInUseLocks,
+      args.inUseReleaseRequestedAt,
+      args.inUsePurpose,
+      args.inUseReference
     )
   }
 
@@ -154,6 +190,17 @@ export class MintManager implements MintManagerArgs {
       authority: this.authority.toBase58(),
       ruleset: this.ruleset.toBase58(),
       inUseBy: this.inUseBy,
+      authorityRenounced: this.authorityRenounced,
+      collectionManager: this.collectionManager,
+      kind: 'MintManagerKind.' + MintManagerKind[this.kind as MintManagerKind],
+      recallDisabled: this.recallDisabled,
+      suspended: this.suspended,
+      inUseUntil: this.inUseUntil,
+      additionalInUseLocks: this.additionalInUseLocks,
+      inUseReleaseRequestedAt: this.inUseReleaseRequestedAt,
+      inUsePurpose:
+        'InUsePurpose.' + InUsePurpose[this.inUsePurpose as InUsePurpose],
+      inUseReference: this.inUseReference,
     }
   }
 }
@@ -173,6 +220,16 @@ export const mintManagerBeet = new beet.FixableBeetStruct<
     ['authority', beetSolana.publicKey],
     ['ruleset', beetSolana.publicKey],
     ['inUseBy', beet.coption(beetSolana.publicKey)],
+    ['authorityRenounced', beet.bool],
+    ['collectionManager', beet.coption(beetSolana.publicKey)],
+    ['kind', mintManagerKindBeet],
+    ['recallDisabled', beet.bool],
+    ['suspended', beet.bool],
+    ['inUseUntil', beet.coption(beet.i64)],
+    ['additionalInUseLocks', beet.array(inUseLockBeet)],
+    ['inUseReleaseRequestedAt', beet.coption(beet.i64)],
+    ['inUsePurpose', inUsePurposeBeet],
+    ['inUseReference', beet.coption(beetSolana.publicKey)],
   ],
   MintManager.fromArgs,
   'MintManager'
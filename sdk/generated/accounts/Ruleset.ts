@@ -8,6 +8,14 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  RulesetCategory,
+  rulesetCategoryBeet,
+} from '../types/RulesetCategory'
+import {
+  RequiredInstruction,
+  requiredInstructionBeet,
+} from '../types/RequiredInstruction'
 
 /**
  * Arguments used to create {@link Ruleset}
@@ -22,6 +30,22 @@ export type RulesetArgs = {
   allowedPrograms: web3.PublicKey[]
   disallowedAddresses: web3.PublicKey[]
   extensions: web3.PublicKey[]
+  locked: boolean
+  requireAttachmentApproval: boolean
+  approvedCreators: web3.PublicKey[]
+  uri: string
+  descriptionHash: number[] /* size: 32 */
+  category: RulesetCategory
+  minimumCreatorBasisPoints: number
+  requiredCreators: web3.PublicKey[]
+  requireRoyaltyPayment: boolean
+  paymentMints: web3.PublicKey[]
+  preTransferInstructions: RequiredInstruction[]
+  postTransferInstructions: RequiredInstruction[]
+  allowRecall: boolean
+  disallowSuspension: boolean
+  inUseReleaseGracePeriodSeconds: beet.COption<beet.bignum>
+  allowAuthorityRemoveInUseBy: boolean
 }
 /**
  * Holds the data for the {@link Ruleset} Account and provides de/serialization
@@ -38,7 +62,23 @@ export class Ruleset implements RulesetArgs {
     readonly name: string,
     readonly allowedPrograms: web3.PublicKey[],
     readonly disallowedAddresses: web3.PublicKey[],
-    readonly extensions: web3.PublicKey[]
+    readonly extensions: web3.PublicKey[],
+    readonly locked: boolean,
+    readonly requireAttachmentApproval: boolean,
+    readonly approvedCreators: web3.PublicKey[],
+    readonly uri: string,
+    readonly descriptionHash: number[] /* size: 32 */,
+    readonly category: RulesetCategory,
+    readonly minimumCreatorBasisPoints: number,
+    readonly requiredCreators: web3.PublicKey[],
+    readonly requireRoyaltyPayment: boolean,
+    readonly paymentMints: web3.PublicKey[],
+    readonly preTransferInstructions: RequiredInstruction[],
+    readonly postTransferInstructions: RequiredInstruction[],
+    readonly allowRecall: boolean,
+    readonly disallowSuspension: boolean,
+    readonly inUseReleaseGracePeriodSeconds: beet.COption<beet.bignum>,
+    readonly allowAuthorityRemoveInUseBy: boolean
   ) {}
 
   /**
@@ -52,7 +92,23 @@ export class Ruleset implements RulesetArgs {
       args.name,
       args.allowedPrograms,
       args.disallowedAddresses,
-      args.extensions
+      args.extensions,
+      args.locked,
+      args.requireAttachmentApproval,
+      args.approvedCreators,
+      args.uri,
+      args.descriptionHash,
+      args.category,
+      args.minimumCreatorBasisPoints,
+      args.requiredCreators,
+      args.requireRoyaltyPayment,
+      args.paymentMints,
+      args.preTransferInstructions,
+      args.postTransferInstructions,
+      args.allowRecall,
+      args.disallowSuspension,
+      args.inUseReleaseGracePeriodSeconds,
+      args.allowAuthorityRemoveInUseBy
     )
   }
 
@@ -158,6 +214,23 @@ export class Ruleset implements RulesetArgs {
       allowedPrograms: this.allowedPrograms,
       disallowedAddresses: this.disallowedAddresses,
       extensions: this.extensions,
+      locked: this.locked,
+      requireAttachmentApproval: this.requireAttachmentApproval,
+      approvedCreators: this.approvedCreators,
+      uri: this.uri,
+      descriptionHash: this.descriptionHash,
+      category:
+        'RulesetCategory.' + RulesetCategory[this.category as RulesetCategory],
+      minimumCreatorBasisPoints: this.minimumCreatorBasisPoints,
+      requiredCreators: this.requiredCreators,
+      requireRoyaltyPayment: this.requireRoyaltyPayment,
+      paymentMints: this.paymentMints,
+      preTransferInstructions: this.preTransferInstructions,
+      postTransferInstructions: this.postTransferInstructions,
+      allowRecall: this.allowRecall,
+      disallowSuspension: this.disallowSuspension,
+      inUseReleaseGracePeriodSeconds: this.inUseReleaseGracePeriodSeconds,
+      allowAuthorityRemoveInUseBy: this.allowAuthorityRemoveInUseBy,
     }
   }
 }
@@ -175,6 +248,22 @@ export const rulesetBeet = new beet.FixableBeetStruct<Ruleset, RulesetArgs>(
     ['allowedPrograms', beet.array(beetSolana.publicKey)],
     ['disallowedAddresses', beet.array(beetSolana.publicKey)],
     ['extensions', beet.array(beetSolana.publicKey)],
+    ['locked', beet.bool],
+    ['requireAttachmentApproval', beet.bool],
+    ['approvedCreators', beet.array(beetSolana.publicKey)],
+    ['uri', beet.utf8String],
+    ['descriptionHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['category', rulesetCategoryBeet],
+    ['minimumCreatorBasisPoints', beet.u16],
+    ['requiredCreators', beet.array(beetSolana.publicKey)],
+    ['requireRoyaltyPayment', beet.bool],
+    ['paymentMints', beet.array(beetSolana.publicKey)],
+    ['preTransferInstructions', beet.array(requiredInstructionBeet)],
+    ['postTransferInstructions', beet.array(requiredInstructionBeet)],
+    ['allowRecall', beet.bool],
+    ['disallowSuspension', beet.bool],
+    ['inUseReleaseGracePeriodSeconds', beet.coption(beet.i64)],
+    ['allowAuthorityRemoveInUseBy', beet.bool],
   ],
   Ruleset.fromArgs,
   'Ruleset'
@@ -1,7 +1,19 @@
+export * from './CollectionManager'
+export * from './InUseRecord'
 export * from './MintManager'
+export * from './Rental'
 export * from './Ruleset'
 
+import { CollectionManager } from './CollectionManager'
+import { InUseRecord } from './InUseRecord'
 import { MintManager } from './MintManager'
+import { Rental } from './Rental'
 import { Ruleset } from './Ruleset'
 
-export const accountProviders = { MintManager, Ruleset }
+export const accountProviders = {
+  CollectionManager,
+  InUseRecord,
+  MintManager,
+  Rental,
+  Ruleset,
+}
@@ -641,6 +641,774 @@ createErrorFromNameLookup.set(
   () => new NotEnoughRemainingAccountsError()
 )
 
+/**
+ * RulesetLocked: 'Ruleset is locked'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RulesetLockedError extends Error {
+  readonly code: number = 0x178b
+  readonly name: string = 'RulesetLocked'
+  constructor() {
+    super('Ruleset is locked')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RulesetLockedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new RulesetLockedError())
+createErrorFromNameLookup.set('RulesetLocked', () => new RulesetLockedError())
+
+/**
+ * MintManagerAuthorityRenounced: 'Mint manager authority has been renounced'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintManagerAuthorityRenouncedError extends Error {
+  readonly code: number = 0x178c
+  readonly name: string = 'MintManagerAuthorityRenounced'
+  constructor() {
+    super('Mint manager authority has been renounced')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintManagerAuthorityRenouncedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x178c,
+  () => new MintManagerAuthorityRenouncedError()
+)
+createErrorFromNameLookup.set(
+  'MintManagerAuthorityRenounced',
+  () => new MintManagerAuthorityRenouncedError()
+)
+
+/**
+ * RulesetAttachmentNotApproved: 'Attaching to this ruleset requires approval'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RulesetAttachmentNotApprovedError extends Error {
+  readonly code: number = 0x178d
+  readonly name: string = 'RulesetAttachmentNotApproved'
+  constructor() {
+    super('Attaching to this ruleset requires approval')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RulesetAttachmentNotApprovedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x178d,
+  () => new RulesetAttachmentNotApprovedError()
+)
+createErrorFromNameLookup.set(
+  'RulesetAttachmentNotApproved',
+  () => new RulesetAttachmentNotApprovedError()
+)
+
+/**
+ * RequiredCreatorNotVerified: 'Required creator not verified in mint metadata'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RequiredCreatorNotVerifiedError extends Error {
+  readonly code: number = 0x178e
+  readonly name: string = 'RequiredCreatorNotVerified'
+  constructor() {
+    super('Required creator not verified in mint metadata')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RequiredCreatorNotVerifiedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x178e,
+  () => new RequiredCreatorNotVerifiedError()
+)
+createErrorFromNameLookup.set(
+  'RequiredCreatorNotVerified',
+  () => new RequiredCreatorNotVerifiedError()
+)
+
+/**
+ * RoyaltyPaymentRequired: 'Ruleset requires royalty payment on transfer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RoyaltyPaymentRequiredError extends Error {
+  readonly code: number = 0x178f
+  readonly name: string = 'RoyaltyPaymentRequired'
+  constructor() {
+    super('Ruleset requires royalty payment on transfer')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RoyaltyPaymentRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new RoyaltyPaymentRequiredError())
+createErrorFromNameLookup.set(
+  'RoyaltyPaymentRequired',
+  () => new RoyaltyPaymentRequiredError()
+)
+
+/**
+ * InvalidRoyaltyCreator: 'Invalid royalty creator account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRoyaltyCreatorError extends Error {
+  readonly code: number = 0x1790
+  readonly name: string = 'InvalidRoyaltyCreator'
+  constructor() {
+    super('Invalid royalty creator account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRoyaltyCreatorError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new InvalidRoyaltyCreatorError())
+createErrorFromNameLookup.set(
+  'InvalidRoyaltyCreator',
+  () => new InvalidRoyaltyCreatorError()
+)
+
+/**
+ * PaymentMintNotAllowed: 'Payment mint not allowed by ruleset'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaymentMintNotAllowedError extends Error {
+  readonly code: number = 0x1791
+  readonly name: string = 'PaymentMintNotAllowed'
+  constructor() {
+    super('Payment mint not allowed by ruleset')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaymentMintNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new PaymentMintNotAllowedError())
+createErrorFromNameLookup.set(
+  'PaymentMintNotAllowed',
+  () => new PaymentMintNotAllowedError()
+)
+
+/**
+ * InvalidCollectionManager: 'Invalid collection manager'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCollectionManagerError extends Error {
+  readonly code: number = 0x1792
+  readonly name: string = 'InvalidCollectionManager'
+  constructor() {
+    super('Invalid collection manager')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCollectionManagerError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new InvalidCollectionManagerError())
+createErrorFromNameLookup.set(
+  'InvalidCollectionManager',
+  () => new InvalidCollectionManagerError()
+)
+
+/**
+ * MintNotInCollection: 'Mint is not a verified member of the collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintNotInCollectionError extends Error {
+  readonly code: number = 0x1793
+  readonly name: string = 'MintNotInCollection'
+  constructor() {
+    super('Mint is not a verified member of the collection')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintNotInCollectionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new MintNotInCollectionError())
+createErrorFromNameLookup.set(
+  'MintNotInCollection',
+  () => new MintNotInCollectionError()
+)
+
+/**
+ * RecallNotAllowed: 'Recall is not allowed by ruleset'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecallNotAllowedError extends Error {
+  readonly code: number = 0x1794
+  readonly name: string = 'RecallNotAllowed'
+  constructor() {
+    super('Recall is not allowed by ruleset')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecallNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new RecallNotAllowedError())
+createErrorFromNameLookup.set(
+  'RecallNotAllowed',
+  () => new RecallNotAllowedError()
+)
+
+/**
+ * RecallDisabled: 'Recall has been permanently disabled for this mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecallDisabledError extends Error {
+  readonly code: number = 0x1795
+  readonly name: string = 'RecallDisabled'
+  constructor() {
+    super('Recall has been permanently disabled for this mint')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecallDisabledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new RecallDisabledError())
+createErrorFromNameLookup.set('RecallDisabled', () => new RecallDisabledError())
+
+/**
+ * RecallNotSupported: 'Mint does not delegate recall to the mint manager'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecallNotSupportedError extends Error {
+  readonly code: number = 0x1796
+  readonly name: string = 'RecallNotSupported'
+  constructor() {
+    super('Mint does not delegate recall to the mint manager')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecallNotSupportedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new RecallNotSupportedError())
+createErrorFromNameLookup.set(
+  'RecallNotSupported',
+  () => new RecallNotSupportedError()
+)
+
+/**
+ * TokenSuspended: 'Token is suspended by the mint manager authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenSuspendedError extends Error {
+  readonly code: number = 0x1797
+  readonly name: string = 'TokenSuspended'
+  constructor() {
+    super('Token is suspended by the mint manager authority')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenSuspendedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new TokenSuspendedError())
+createErrorFromNameLookup.set('TokenSuspended', () => new TokenSuspendedError())
+
+/**
+ * TokenNotSuspended: 'Token is not suspended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenNotSuspendedError extends Error {
+  readonly code: number = 0x1798
+  readonly name: string = 'TokenNotSuspended'
+  constructor() {
+    super('Token is not suspended')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenNotSuspendedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new TokenNotSuspendedError())
+createErrorFromNameLookup.set(
+  'TokenNotSuspended',
+  () => new TokenNotSuspendedError()
+)
+
+/**
+ * SuspensionNotAllowed: 'Suspension is not allowed by ruleset'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SuspensionNotAllowedError extends Error {
+  readonly code: number = 0x1799
+  readonly name: string = 'SuspensionNotAllowed'
+  constructor() {
+    super('Suspension is not allowed by ruleset')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SuspensionNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new SuspensionNotAllowedError())
+createErrorFromNameLookup.set(
+  'SuspensionNotAllowed',
+  () => new SuspensionNotAllowedError()
+)
+
+/**
+ * InvalidRental: 'Invalid rental'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRentalError extends Error {
+  readonly code: number = 0x179a
+  readonly name: string = 'InvalidRental'
+  constructor() {
+    super('Invalid rental')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRentalError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new InvalidRentalError())
+createErrorFromNameLookup.set('InvalidRental', () => new InvalidRentalError())
+
+/**
+ * RentalAlreadyRented: 'Token is already rented'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RentalAlreadyRentedError extends Error {
+  readonly code: number = 0x179b
+  readonly name: string = 'RentalAlreadyRented'
+  constructor() {
+    super('Token is already rented')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RentalAlreadyRentedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new RentalAlreadyRentedError())
+createErrorFromNameLookup.set(
+  'RentalAlreadyRented',
+  () => new RentalAlreadyRentedError()
+)
+
+/**
+ * RentalNotRented: 'Token is not rented'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RentalNotRentedError extends Error {
+  readonly code: number = 0x179c
+  readonly name: string = 'RentalNotRented'
+  constructor() {
+    super('Token is not rented')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RentalNotRentedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new RentalNotRentedError())
+createErrorFromNameLookup.set(
+  'RentalNotRented',
+  () => new RentalNotRentedError()
+)
+
+/**
+ * RentalNotExpired: 'Rental has not expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RentalNotExpiredError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'RentalNotExpired'
+  constructor() {
+    super('Rental has not expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RentalNotExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new RentalNotExpiredError())
+createErrorFromNameLookup.set(
+  'RentalNotExpired',
+  () => new RentalNotExpiredError()
+)
+
+/**
+ * InvalidInUseUntil: 'In use until must be in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInUseUntilError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'InvalidInUseUntil'
+  constructor() {
+    super('In use until must be in the future')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInUseUntilError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new InvalidInUseUntilError())
+createErrorFromNameLookup.set(
+  'InvalidInUseUntil',
+  () => new InvalidInUseUntilError()
+)
+
+/**
+ * TooManyInUseLocks: 'Too many in use locks'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyInUseLocksError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'TooManyInUseLocks'
+  constructor() {
+    super('Too many in use locks')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyInUseLocksError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new TooManyInUseLocksError())
+createErrorFromNameLookup.set(
+  'TooManyInUseLocks',
+  () => new TooManyInUseLocksError()
+)
+
+/**
+ * InUseReleaseNotAllowed: 'In use release not allowed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InUseReleaseNotAllowedError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'InUseReleaseNotAllowed'
+  constructor() {
+    super('In use release not allowed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InUseReleaseNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new InUseReleaseNotAllowedError())
+createErrorFromNameLookup.set(
+  'InUseReleaseNotAllowed',
+  () => new InUseReleaseNotAllowedError()
+)
+
+/**
+ * InUseLockNotFound: 'In use lock not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InUseLockNotFoundError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'InUseLockNotFound'
+  constructor() {
+    super('In use lock not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InUseLockNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new InUseLockNotFoundError())
+createErrorFromNameLookup.set(
+  'InUseLockNotFound',
+  () => new InUseLockNotFoundError()
+)
+
+/**
+ * InUseReleaseAlreadyRequested: 'In use release already requested'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InUseReleaseAlreadyRequestedError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'InUseReleaseAlreadyRequested'
+  constructor() {
+    super('In use release already requested')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InUseReleaseAlreadyRequestedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a2,
+  () => new InUseReleaseAlreadyRequestedError()
+)
+createErrorFromNameLookup.set(
+  'InUseReleaseAlreadyRequested',
+  () => new InUseReleaseAlreadyRequestedError()
+)
+
+/**
+ * InUseReleaseNotRequested: 'In use release not requested'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InUseReleaseNotRequestedError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'InUseReleaseNotRequested'
+  constructor() {
+    super('In use release not requested')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InUseReleaseNotRequestedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new InUseReleaseNotRequestedError())
+createErrorFromNameLookup.set(
+  'InUseReleaseNotRequested',
+  () => new InUseReleaseNotRequestedError()
+)
+
+/**
+ * InUseReleaseGracePeriodActive: 'In use release grace period has not ended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InUseReleaseGracePeriodActiveError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'InUseReleaseGracePeriodActive'
+  constructor() {
+    super('In use release grace period has not ended')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InUseReleaseGracePeriodActiveError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a4,
+  () => new InUseReleaseGracePeriodActiveError()
+)
+createErrorFromNameLookup.set(
+  'InUseReleaseGracePeriodActive',
+  () => new InUseReleaseGracePeriodActiveError()
+)
+
+/**
+ * AuthorityRemoveInUseByNotAllowed: 'Authority remove in use by not allowed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuthorityRemoveInUseByNotAllowedError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'AuthorityRemoveInUseByNotAllowed'
+  constructor() {
+    super('Authority remove in use by not allowed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuthorityRemoveInUseByNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a5,
+  () => new AuthorityRemoveInUseByNotAllowedError()
+)
+createErrorFromNameLookup.set(
+  'AuthorityRemoveInUseByNotAllowed',
+  () => new AuthorityRemoveInUseByNotAllowedError()
+)
+
+/**
+ * MigrationRequired: 'Account must be migrated to the current version'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MigrationRequiredError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'MigrationRequired'
+  constructor() {
+    super('Account must be migrated to the current version')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MigrationRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new MigrationRequiredError())
+createErrorFromNameLookup.set(
+  'MigrationRequired',
+  () => new MigrationRequiredError()
+)
+
+/**
+ * MintManagerInCollection: 'Mint manager belongs to a collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintManagerInCollectionError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'MintManagerInCollection'
+  constructor() {
+    super('Mint manager belongs to a collection')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintManagerInCollectionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new MintManagerInCollectionError())
+createErrorFromNameLookup.set(
+  'MintManagerInCollection',
+  () => new MintManagerInCollectionError()
+)
+
+/**
+ * InvalidCollectionRuleset: 'Collection ruleset cannot require creator basis points or creators'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCollectionRulesetError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'InvalidCollectionRuleset'
+  constructor() {
+    super('Collection ruleset cannot require creator basis points or creators')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCollectionRulesetError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new InvalidCollectionRulesetError())
+createErrorFromNameLookup.set(
+  'InvalidCollectionRuleset',
+  () => new InvalidCollectionRulesetError()
+)
+
+/**
+ * InUseRecordNotInitialized: 'In use record must be initialized'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InUseRecordNotInitializedError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'InUseRecordNotInitialized'
+  constructor() {
+    super('In use record must be initialized')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InUseRecordNotInitializedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a9,
+  () => new InUseRecordNotInitializedError()
+)
+createErrorFromNameLookup.set(
+  'InUseRecordNotInitialized',
+  () => new InUseRecordNotInitializedError()
+)
+
+/**
+ * RecallDuringRental: 'Token cannot be recalled while it is listed or rented'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecallDuringRentalError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'RecallDuringRental'
+  constructor() {
+    super('Token cannot be recalled while it is listed or rented')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecallDuringRentalError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new RecallDuringRentalError())
+createErrorFromNameLookup.set(
+  'RecallDuringRental',
+  () => new RecallDuringRentalError()
+)
+
+/**
+ * HolderDoesNotHoldSupply: 'Holder token account must hold the entire supply'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class HolderDoesNotHoldSupplyError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'HolderDoesNotHoldSupply'
+  constructor() {
+    super('Holder token account must hold the entire supply')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, HolderDoesNotHoldSupplyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new HolderDoesNotHoldSupplyError())
+createErrorFromNameLookup.set(
+  'HolderDoesNotHoldSupply',
+  () => new HolderDoesNotHoldSupplyError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
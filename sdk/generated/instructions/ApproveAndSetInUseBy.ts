@@ -26,7 +26,7 @@ export type ApproveAndSetInUseByInstructionArgs = {
  * @category ApproveAndSetInUseBy
  * @category generated
  */
-export const ApproveAndSetInUseByStruct = new beet.BeetArgsStruct<
+export const ApproveAndSetInUseByStruct = new beet.FixableBeetArgsStruct<
   ApproveAndSetInUseByInstructionArgs & {
     instructionDiscriminator: number
   }
@@ -8,15 +8,32 @@
 import * as splToken from '@solana/spl-token'
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { BurnIx, burnIxBeet } from '../types/BurnIx'
 
 /**
  * @category Instructions
  * @category Burn
  * @category generated
  */
-export const BurnStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number
-}>([['instructionDiscriminator', beet.u8]], 'BurnInstructionArgs')
+export type BurnInstructionArgs = {
+  burnIx: BurnIx
+}
+/**
+ * @category Instructions
+ * @category Burn
+ * @category generated
+ */
+export const BurnStruct = new beet.BeetArgsStruct<
+  BurnInstructionArgs & {
+    instructionDiscriminator: number
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.u8],
+    ['burnIx', burnIxBeet],
+  ],
+  'BurnInstructionArgs'
+)
 /**
  * Accounts required by the _Burn_ instruction
  *
@@ -43,16 +60,20 @@ export const burnInstructionDiscriminator = 9
  * Creates a _Burn_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
  * @category Instructions
  * @category Burn
  * @category generated
  */
 export function createBurnInstruction(
   accounts: BurnInstructionAccounts,
+  args: BurnInstructionArgs,
   programId = new web3.PublicKey('ccsxqYAg64wuLEh45KabyPvkKjrEfDPsDZUQrGn7mf3')
 ) {
   const [data] = BurnStruct.serialize({
     instructionDiscriminator: burnInstructionDiscriminator,
+    ...args,
   })
   const keys: web3.AccountMeta[] = [
     {
@@ -8,15 +8,32 @@
 import * as splToken from '@solana/spl-token'
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { InitMintManagerIx, initMintManagerIxBeet } from '../types/InitMintManagerIx'
 
 /**
  * @category Instructions
  * @category InitMintManager
  * @category generated
  */
-export const InitMintManagerStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number
-}>([['instructionDiscriminator', beet.u8]], 'InitMintManagerInstructionArgs')
+export type InitMintManagerInstructionArgs = {
+  initMintManagerIx: InitMintManagerIx
+}
+/**
+ * @category Instructions
+ * @category InitMintManager
+ * @category generated
+ */
+export const InitMintManagerStruct = new beet.BeetArgsStruct<
+  InitMintManagerInstructionArgs & {
+    instructionDiscriminator: number
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.u8],
+    ['initMintManagerIx', initMintManagerIxBeet],
+  ],
+  'InitMintManagerInstructionArgs'
+)
 /**
  * Accounts required by the _InitMintManager_ instruction
  *
@@ -51,16 +68,20 @@ export const initMintManagerInstructionDiscriminator = 3
  * Creates a _InitMintManager_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
  * @category Instructions
  * @category InitMintManager
  * @category generated
  */
 export function createInitMintManagerInstruction(
   accounts: InitMintManagerInstructionAccounts,
+  args: InitMintManagerInstructionArgs,
   programId = new web3.PublicKey('ccsxqYAg64wuLEh45KabyPvkKjrEfDPsDZUQrGn7mf3')
 ) {
   const [data] = InitMintManagerStruct.serialize({
     instructionDiscriminator: initMintManagerInstructionDiscriminator,
+    ...args,
   })
   const keys: web3.AccountMeta[] = [
     {
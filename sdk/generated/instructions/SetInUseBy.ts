@@ -7,15 +7,32 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { SetInUseByIx, setInUseByIxBeet } from '../types/SetInUseByIx'
 
 /**
  * @category Instructions
  * @category SetInUseBy
  * @category generated
  */
-export const SetInUseByStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number
-}>([['instructionDiscriminator', beet.u8]], 'SetInUseByInstructionArgs')
+export type SetInUseByInstructionArgs = {
+  setInUseByIx: SetInUseByIx
+}
+/**
+ * @category Instructions
+ * @category SetInUseBy
+ * @category generated
+ */
+export const SetInUseByStruct = new beet.FixableBeetArgsStruct<
+  SetInUseByInstructionArgs & {
+    instructionDiscriminator: number
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.u8],
+    ['setInUseByIx', setInUseByIxBeet],
+  ],
+  'SetInUseByInstructionArgs'
+)
 /**
  * Accounts required by the _SetInUseBy_ instruction
  *
@@ -42,16 +59,20 @@ export const setInUseByInstructionDiscriminator = 5
  * Creates a _SetInUseBy_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
  * @category Instructions
  * @category SetInUseBy
  * @category generated
  */
 export function createSetInUseByInstruction(
   accounts: SetInUseByInstructionAccounts,
+  args: SetInUseByInstructionArgs,
   programId = new web3.PublicKey('ccsxqYAg64wuLEh45KabyPvkKjrEfDPsDZUQrGn7mf3')
 ) {
   const [data] = SetInUseByStruct.serialize({
     instructionDiscriminator: setInUseByInstructionDiscriminator,
+    ...args,
   })
   const keys: web3.AccountMeta[] = [
     {
@@ -8,15 +8,32 @@
 import * as splToken from '@solana/spl-token'
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { TransferIx, transferIxBeet } from '../types/TransferIx'
 
 /**
  * @category Instructions
  * @category Transfer
  * @category generated
  */
-export const TransferStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number
-}>([['instructionDiscriminator', beet.u8]], 'TransferInstructionArgs')
+export type TransferInstructionArgs = {
+  transferIx: TransferIx
+}
+/**
+ * @category Instructions
+ * @category Transfer
+ * @category generated
+ */
+export const TransferStruct = new beet.BeetArgsStruct<
+  TransferInstructionArgs & {
+    instructionDiscriminator: number
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.u8],
+    ['transferIx', transferIxBeet],
+  ],
+  'TransferInstructionArgs'
+)
 /**
  * Accounts required by the _Transfer_ instruction
  *
@@ -51,16 +68,20 @@ export const transferInstructionDiscriminator = 13
  * Creates a _Transfer_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
  * @category Instructions
  * @category Transfer
  * @category generated
  */
 export function createTransferInstruction(
   accounts: TransferInstructionAccounts,
+  args: TransferInstructionArgs,
   programId = new web3.PublicKey('ccsxqYAg64wuLEh45KabyPvkKjrEfDPsDZUQrGn7mf3')
 ) {
   const [data] = TransferStruct.serialize({
     instructionDiscriminator: transferInstructionDiscriminator,
+    ...args,
   })
   const keys: web3.AccountMeta[] = [
     {
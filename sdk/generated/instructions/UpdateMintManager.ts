@@ -43,6 +43,7 @@ export const UpdateMintManagerStruct = new beet.BeetArgsStruct<
  * @property [] ruleset
  * @property [**signer**] authority
  * @property [_writable_, **signer**] payer
+ * @property [] mintMetadata Mint metadata
  * @category Instructions
  * @category UpdateMintManager
  * @category generated
@@ -53,6 +54,7 @@ export type UpdateMintManagerInstructionAccounts = {
   authority: web3.PublicKey
   payer: web3.PublicKey
   systemProgram?: web3.PublicKey
+  mintMetadata: web3.PublicKey
 }
 
 export const updateMintManagerInstructionDiscriminator = 4
@@ -102,6 +104,11 @@ export function createUpdateMintManagerInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.mintMetadata,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -1,15 +1,41 @@
+export * from './AddInUseLock'
 export * from './Approve'
 export * from './ApproveAndSetInUseBy'
+export * from './AuthorityRemoveInUseBy'
+export * from './BatchInitMintManager'
 export * from './Burn'
+export * from './CancelRental'
+export * from './CloneRuleset'
 export * from './Close'
 export * from './CloseMintManager'
 export * from './CloseRuleset'
+export * from './DisableRecall'
+export * from './ForceRemoveInUseBy'
+export * from './InitCollectionManager'
+export * from './InitInUseRecord'
 export * from './InitMintManager'
 export * from './InitRuleset'
 export * from './InitializeAccount'
+export * from './ListRental'
+export * from './LockRuleset'
+export * from './MigrateMintManager'
+export * from './MigrateRuleset'
+export * from './MintNewToken'
+export * from './Recall'
+export * from './ReclaimRental'
 export * from './RemoveInUseBy'
+export * from './RenewInUseBy'
+export * from './RenounceMintManagerAuthority'
+export * from './RentToken'
+export * from './RequestInUseRelease'
 export * from './Revoke'
 export * from './SetInUseBy'
+export * from './SuspendToken'
 export * from './Transfer'
+export * from './TransferByInUser'
+export * from './TransferWithPayment'
+export * from './TransferWithTokenPayment'
+export * from './UnsuspendToken'
+export * from './UpdateCollectionManager'
 export * from './UpdateMintManager'
 export * from './UpdateRuleset'
@@ -13,6 +13,9 @@ import * as beet from '@metaplex-foundation/beet'
 export enum AccountType {
   Ruleset,
   MintManager,
+  CollectionManager,
+  InUseRecord,
+  Rental,
   Unrecognized,
 }
 
@@ -5,9 +5,15 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { InUsePurpose, inUsePurposeBeet } from './InUsePurpose'
 export type ApproveAndSetInUseByIx = {
   amount: beet.bignum
+  inUseUntil: beet.COption<beet.bignum>
+  purpose: InUsePurpose
+  reference: beet.COption<web3.PublicKey>
 }
 
 /**
@@ -15,7 +21,12 @@ export type ApproveAndSetInUseByIx = {
  * @category generated
  */
 export const approveAndSetInUseByIxBeet =
-  new beet.BeetArgsStruct<ApproveAndSetInUseByIx>(
-    [['amount', beet.u64]],
+  new beet.FixableBeetArgsStruct<ApproveAndSetInUseByIx>(
+    [
+      ['amount', beet.u64],
+      ['inUseUntil', beet.coption(beet.i64)],
+      ['purpose', inUsePurposeBeet],
+      ['reference', beet.coption(beetSolana.publicKey)],
+    ],
     'ApproveAndSetInUseByIx'
   )
@@ -8,11 +8,34 @@
 import * as web3 from '@solana/web3.js'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import {
+  RulesetCategory,
+  rulesetCategoryBeet,
+} from './RulesetCategory'
+import {
+  RequiredInstruction,
+  requiredInstructionBeet,
+} from './RequiredInstruction'
 export type UpdateRulesetIx = {
   authority: web3.PublicKey
   allowedPrograms: web3.PublicKey[]
   disallowedAddresses: web3.PublicKey[]
   extensions: web3.PublicKey[]
+  requireAttachmentApproval: boolean
+  approvedCreators: web3.PublicKey[]
+  uri: string
+  descriptionHash: number[] /* size: 32 */
+  category: RulesetCategory
+  minimumCreatorBasisPoints: number
+  requiredCreators: web3.PublicKey[]
+  requireRoyaltyPayment: boolean
+  paymentMints: web3.PublicKey[]
+  preTransferInstructions: RequiredInstruction[]
+  postTransferInstructions: RequiredInstruction[]
+  allowRecall: boolean
+  disallowSuspension: boolean
+  inUseReleaseGracePeriodSeconds: beet.COption<beet.bignum>
+  allowAuthorityRemoveInUseBy: boolean
 }
 
 /**
@@ -26,6 +49,21 @@ export const updateRulesetIxBeet =
       ['allowedPrograms', beet.array(beetSolana.publicKey)],
       ['disallowedAddresses', beet.array(beetSolana.publicKey)],
       ['extensions', beet.array(beetSolana.publicKey)],
+      ['requireAttachmentApproval', beet.bool],
+      ['approvedCreators', beet.array(beetSolana.publicKey)],
+      ['uri', beet.utf8String],
+      ['descriptionHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ['category', rulesetCategoryBeet],
+      ['minimumCreatorBasisPoints', beet.u16],
+      ['requiredCreators', beet.array(beetSolana.publicKey)],
+      ['requireRoyaltyPayment', beet.bool],
+      ['paymentMints', beet.array(beetSolana.publicKey)],
+      ['preTransferInstructions', beet.array(requiredInstructionBeet)],
+      ['postTransferInstructions', beet.array(requiredInstructionBeet)],
+      ['allowRecall', beet.bool],
+      ['disallowSuspension', beet.bool],
+      ['inUseReleaseGracePeriodSeconds', beet.coption(beet.i64)],
+      ['allowAuthorityRemoveInUseBy', beet.bool],
     ],
     'UpdateRulesetIx'
   )
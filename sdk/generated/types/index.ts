@@ -1,6 +1,24 @@
 export * from './AccountType'
+export * from './AddInUseLockIx'
 export * from './ApproveAndSetInUseByIx'
 export * from './ApproveIx'
+export * from './BurnIx'
+export * from './CloneRulesetIx'
+export * from './InUseLock'
+export * from './InUsePurpose'
+export * from './InitMintManagerIx'
 export * from './InitRulesetIx'
+export * from './ListRentalIx'
+export * from './MintManagerKind'
+export * from './MintNewTokenIx'
+export * from './RecallIx'
+export * from './RequiredInstruction'
+export * from './RulesetCategory'
+export * from './SetInUseByIx'
+export * from './TransferByInUserIx'
+export * from './TransferIx'
+export * from './TransferWithPaymentIx'
+export * from './TransferWithTokenPaymentIx'
+export * from './UpdateCollectionManagerIx'
 export * from './UpdateMintManagerIx'
 export * from './UpdateRulesetIx'
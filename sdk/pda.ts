@@ -22,6 +22,32 @@ export const findMintManagerId = (mintId: PublicKey): PublicKey => {
   )[0];
 };
 
+export const findCollectionManagerId = (collectionId: PublicKey): PublicKey => {
+  return findProgramAddressSync(
+    [utils.bytes.utf8.encode("collection-manager"), collectionId.toBuffer()],
+    PROGRAM_ID,
+  )[0];
+};
+
+export const findInUseRecordId = (
+  holderTokenAccountId: PublicKey,
+): PublicKey => {
+  return findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("in-use-record"),
+      holderTokenAccountId.toBuffer(),
+    ],
+    PROGRAM_ID,
+  )[0];
+};
+
+export const findRentalId = (mintId: PublicKey): PublicKey => {
+  return findProgramAddressSync(
+    [utils.bytes.utf8.encode("rental"), mintId.toBuffer()],
+    PROGRAM_ID,
+  )[0];
+};
+
 export const findMintMetadataId = (mintId: PublicKey): PublicKey => {
   return findProgramAddressSync(
     [
@@ -0,0 +1,162 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import { CollectionManager } from "../../sdk/generated/accounts/CollectionManager";
+import { createInitCollectionManagerInstruction } from "../../sdk/generated/instructions/InitCollectionManager";
+import { createUpdateCollectionManagerInstruction } from "../../sdk/generated/instructions/UpdateCollectionManager";
+import {
+  findCollectionManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const collectionKeypair = Keypair.generate();
+const RULESET_ID = findRulesetId();
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  const tx = await createMintTx(
+    provider.connection,
+    collectionKeypair.publicKey,
+    provider.wallet.publicKey,
+  );
+  tx.add(
+    createMintMetadataTx(
+      collectionKeypair.publicKey,
+      provider.wallet.publicKey,
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    collectionKeypair,
+  ]);
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      minimumCreatorBasisPoints: 100,
+    }),
+    provider.wallet,
+  );
+});
+
+test("Init collection manager", async () => {
+  const collectionManagerId = findCollectionManagerId(
+    collectionKeypair.publicKey,
+  );
+  const tx = new Transaction().add(
+    createInitCollectionManagerInstruction({
+      collectionManager: collectionManagerId,
+      collection: collectionKeypair.publicKey,
+      collectionMetadata: findMintMetadataId(collectionKeypair.publicKey),
+      ruleset: RULESET_ID,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    }),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const collectionManager = await CollectionManager.fromAccountAddress(
+    provider.connection,
+    collectionManagerId,
+  );
+  expect(collectionManager.collection.toString()).toBe(
+    collectionKeypair.publicKey.toString(),
+  );
+  expect(collectionManager.authority.toString()).toBe(
+    provider.wallet.publicKey.toString(),
+  );
+  expect(collectionManager.ruleset.toString()).toBe(RULESET_ID.toString());
+});
+
+test("Fail to init collection manager twice", async () => {
+  const tx = new Transaction().add(
+    createInitCollectionManagerInstruction({
+      collectionManager: findCollectionManagerId(collectionKeypair.publicKey),
+      collection: collectionKeypair.publicKey,
+      collectionMetadata: findMintMetadataId(collectionKeypair.publicKey),
+      ruleset: RULESET_ID,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    }),
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet),
+  ).rejects.toThrow();
+});
+
+test("Fail to move collection to a ruleset with creator requirements", async () => {
+  const tx = new Transaction().add(
+    createUpdateCollectionManagerInstruction(
+      {
+        collectionManager: findCollectionManagerId(
+          collectionKeypair.publicKey,
+        ),
+        ruleset: findRulesetId(RULESET_NAME),
+        authority: provider.wallet.publicKey,
+      },
+      {
+        updateCollectionManagerIx: { authority: provider.wallet.publicKey },
+      },
+    ),
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet),
+  ).rejects.toThrow();
+});
+
+test("Update collection manager authority", async () => {
+  const newAuthority = Keypair.generate();
+  const collectionManagerId = findCollectionManagerId(
+    collectionKeypair.publicKey,
+  );
+  const tx = new Transaction().add(
+    createUpdateCollectionManagerInstruction(
+      {
+        collectionManager: collectionManagerId,
+        ruleset: RULESET_ID,
+        authority: provider.wallet.publicKey,
+      },
+      { updateCollectionManagerIx: { authority: newAuthority.publicKey } },
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const collectionManager = await CollectionManager.fromAccountAddress(
+    provider.connection,
+    collectionManagerId,
+  );
+  expect(collectionManager.authority.toString()).toBe(
+    newAuthority.publicKey.toString(),
+  );
+  expect(collectionManager.ruleset.toString()).toBe(RULESET_ID.toString());
+});
+
+test("Fail to update collection manager as the previous authority", async () => {
+  const tx = new Transaction().add(
+    createUpdateCollectionManagerInstruction(
+      {
+        collectionManager: findCollectionManagerId(
+          collectionKeypair.publicKey,
+        ),
+        ruleset: RULESET_ID,
+        authority: provider.wallet.publicKey,
+      },
+      {
+        updateCollectionManagerIx: { authority: provider.wallet.publicKey },
+      },
+    ),
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet),
+  ).rejects.toThrow();
+});
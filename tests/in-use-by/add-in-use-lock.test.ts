@@ -0,0 +1,144 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import {
+  createAddInUseLockInstruction,
+  createRemoveInUseByInstruction,
+  createSetInUseByInstruction,
+  InUsePurpose,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import { findMintManagerId, findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createCCSMintTx, executeTransaction, getProvider } from "../../utils";
+
+const mintKeypair = Keypair.generate();
+const firstInUseByAddress = Keypair.generate();
+const secondInUseByAddress = Keypair.generate();
+const thirdInUseByAddress = Keypair.generate();
+
+const RULESET_ID = findRulesetId();
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    RULESET_ID,
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+const addInUseLockTx = (inUseByAddress: PublicKey) =>
+  new Transaction().add(
+    createAddInUseLockInstruction(
+      {
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        ruleset: RULESET_ID,
+        inUseByAddress,
+        holder: provider.wallet.publicKey,
+        holderTokenAccount: getAssociatedTokenAddressSync(
+          mintKeypair.publicKey,
+          provider.wallet.publicKey,
+        ),
+        payer: provider.wallet.publicKey,
+      },
+      {
+        addInUseLockIx: {
+          inUseUntil: null,
+          purpose: InUsePurpose.Staked,
+          reference: null,
+        },
+      },
+    ),
+  );
+
+test("Add in use locks", async () => {
+  await executeTransaction(
+    provider.connection,
+    addInUseLockTx(firstInUseByAddress.publicKey),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    addInUseLockTx(secondInUseByAddress.publicKey),
+    provider.wallet,
+  );
+
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.inUseBy?.toString()).toBe(
+    firstInUseByAddress.publicKey.toString(),
+  );
+  expect(mintManager.additionalInUseLocks.length).toBe(1);
+  expect(mintManager.additionalInUseLocks[0]!.inUseBy.toString()).toBe(
+    secondInUseByAddress.publicKey.toString(),
+  );
+});
+
+test("Fail to add a second lock for the same address", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      addInUseLockTx(firstInUseByAddress.publicKey),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Set in use by while only additional locks are held", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createRemoveInUseByInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        user: firstInUseByAddress.publicKey,
+      }),
+    ),
+    provider.wallet,
+    [firstInUseByAddress],
+  );
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createSetInUseByInstruction(
+        {
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          ruleset: RULESET_ID,
+          inUseByAddress: thirdInUseByAddress.publicKey,
+          holder: provider.wallet.publicKey,
+          holderTokenAccount: getAssociatedTokenAddressSync(
+            mintKeypair.publicKey,
+            provider.wallet.publicKey,
+          ),
+        },
+        {
+          setInUseByIx: {
+            inUseUntil: null,
+            purpose: InUsePurpose.Staked,
+            reference: null,
+          },
+        },
+      ),
+    ),
+    provider.wallet,
+  );
+
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.inUseBy?.toString()).toBe(
+    thirdInUseByAddress.publicKey.toString(),
+  );
+  expect(mintManager.additionalInUseLocks.length).toBe(1);
+});
@@ -0,0 +1,145 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import {
+  createAuthorityRemoveInUseByInstruction,
+  createSetInUseByInstruction,
+  InUsePurpose,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import { findMintManagerId, findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const DISALLOWED_RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const mintKeypair = Keypair.generate();
+const disallowedMintKeypair = Keypair.generate();
+const inUseByAddress = Keypair.generate();
+
+let provider: SolanaProvider;
+
+const mintInUseTx = async (
+  mint: Keypair,
+  rulesetName: string,
+): Promise<Transaction> => {
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mint.publicKey,
+    provider.wallet.publicKey,
+    findRulesetId(rulesetName),
+  );
+  return tx.add(
+    createSetInUseByInstruction(
+      {
+        mintManager: findMintManagerId(mint.publicKey),
+        ruleset: findRulesetId(rulesetName),
+        inUseByAddress: inUseByAddress.publicKey,
+        holder: provider.wallet.publicKey,
+        holderTokenAccount: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          provider.wallet.publicKey,
+        ),
+      },
+      {
+        setInUseByIx: {
+          inUseUntil: null,
+          purpose: InUsePurpose.Staked,
+          reference: null,
+        },
+      },
+    ),
+  );
+};
+
+beforeAll(async () => {
+  provider = await getProvider();
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      allowAuthorityRemoveInUseBy: true,
+    }),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(DISALLOWED_RULESET_NAME, provider.wallet.publicKey),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    await mintInUseTx(mintKeypair, RULESET_NAME),
+    provider.wallet,
+    [mintKeypair],
+  );
+  await executeTransaction(
+    provider.connection,
+    await mintInUseTx(disallowedMintKeypair, DISALLOWED_RULESET_NAME),
+    provider.wallet,
+    [disallowedMintKeypair],
+  );
+});
+
+const authorityRemoveInUseByTx = (
+  mint: Keypair,
+  rulesetName: string,
+  authority = provider.wallet.publicKey,
+) => {
+  const ix = createAuthorityRemoveInUseByInstruction({
+    ruleset: findRulesetId(rulesetName),
+    inUseByAddress: inUseByAddress.publicKey,
+    authority,
+  });
+  // (mint_manager, collection_manager?, in_use_record?) for each mint manager
+  ix.keys.push({
+    pubkey: findMintManagerId(mint.publicKey),
+    isWritable: true,
+    isSigner: false,
+  });
+  return new Transaction().add(ix);
+};
+
+test("Fail to remove on a ruleset that does not allow it", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      authorityRemoveInUseByTx(disallowedMintKeypair, DISALLOWED_RULESET_NAME),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Fail to remove without the mint manager authority", async () => {
+  const otherAuthority = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      authorityRemoveInUseByTx(
+        mintKeypair,
+        RULESET_NAME,
+        otherAuthority.publicKey,
+      ),
+      provider.wallet,
+      [otherAuthority],
+    ),
+  ).rejects.toThrow();
+});
+
+test("Authority remove in use by", async () => {
+  await executeTransaction(
+    provider.connection,
+    authorityRemoveInUseByTx(mintKeypair, RULESET_NAME),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.inUseBy).toBeNull();
+});
@@ -6,7 +6,11 @@ import {
 } from "@solana/spl-token";
 import { Keypair, Transaction } from "@solana/web3.js";
 
-import { handleRemainingAccountsForRuleset, Ruleset } from "../../sdk";
+import {
+  handleRemainingAccountsForRuleset,
+  InUsePurpose,
+  Ruleset,
+} from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import { createBurnInstruction } from "../../sdk/generated/instructions/Burn";
 import { createSetInUseByInstruction } from "../../sdk/generated/instructions/SetInUseBy";
@@ -78,13 +82,22 @@ test("Set in use by", async () => {
   );
 
   const tx = new Transaction();
-  const ix = createSetInUseByInstruction({
-    mintManager: mintManagerId,
-    ruleset: RULESET_ID,
-    inUseByAddress: inUseByAddress.publicKey,
-    holder: provider.wallet.publicKey,
-    holderTokenAccount: holderAtaId,
-  });
+  const ix = createSetInUseByInstruction(
+    {
+      mintManager: mintManagerId,
+      ruleset: RULESET_ID,
+      inUseByAddress: inUseByAddress.publicKey,
+      holder: provider.wallet.publicKey,
+      holderTokenAccount: holderAtaId,
+    },
+    {
+      setInUseByIx: {
+        inUseUntil: null,
+        purpose: InUsePurpose.Unspecified,
+        reference: null,
+      },
+    },
+  );
   handleRemainingAccountsForRuleset(ix, rulesetData);
   tx.add(ix);
   await executeTransaction(provider.connection, tx, provider.wallet);
@@ -117,12 +130,15 @@ test("Burn mint", async () => {
   expect(holderAta.amount.toString()).toBe("1");
 
   tx.add(
-    createBurnInstruction({
-      mintManager: mintManagerId,
-      mint: mintKeypair.publicKey,
-      holderTokenAccount: holderAtaId,
-      holder: provider.wallet.publicKey,
-    }),
+    createBurnInstruction(
+      {
+        mintManager: mintManagerId,
+        mint: mintKeypair.publicKey,
+        holderTokenAccount: holderAtaId,
+        holder: provider.wallet.publicKey,
+      },
+      { burnIx: { amount: 1 } },
+    ),
   );
   await expect(
     executeTransaction(provider.connection, tx, provider.wallet),
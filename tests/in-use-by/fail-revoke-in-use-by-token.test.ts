@@ -6,7 +6,11 @@ import {
 } from "@solana/spl-token";
 import { Keypair, Transaction } from "@solana/web3.js";
 
-import { handleRemainingAccountsForRuleset, Ruleset } from "../../sdk";
+import {
+  handleRemainingAccountsForRuleset,
+  InUsePurpose,
+  Ruleset,
+} from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import { createApproveInstruction } from "../../sdk/generated/instructions/Approve";
 import { createRevokeInstruction } from "../../sdk/generated/instructions/Revoke";
@@ -122,13 +126,22 @@ test("Set in use by", async () => {
   );
 
   const tx = new Transaction();
-  const ix = createSetInUseByInstruction({
-    mintManager: mintManagerId,
-    ruleset: RULESET_ID,
-    inUseByAddress: inUseByAddress.publicKey,
-    holder: provider.wallet.publicKey,
-    holderTokenAccount: holderAtaId,
-  });
+  const ix = createSetInUseByInstruction(
+    {
+      mintManager: mintManagerId,
+      ruleset: RULESET_ID,
+      inUseByAddress: inUseByAddress.publicKey,
+      holder: provider.wallet.publicKey,
+      holderTokenAccount: holderAtaId,
+    },
+    {
+      setInUseByIx: {
+        inUseUntil: null,
+        purpose: InUsePurpose.Unspecified,
+        reference: null,
+      },
+    },
+  );
   handleRemainingAccountsForRuleset(ix, rulesetData);
   tx.add(ix);
   await executeTransaction(provider.connection, tx, provider.wallet);
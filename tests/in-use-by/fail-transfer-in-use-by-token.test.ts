@@ -11,7 +11,11 @@ import {
   Transaction,
 } from "@solana/web3.js";
 
-import { handleRemainingAccountsForRuleset, Ruleset } from "../../sdk";
+import {
+  handleRemainingAccountsForRuleset,
+  InUsePurpose,
+  Ruleset,
+} from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import { createSetInUseByInstruction } from "../../sdk/generated/instructions/SetInUseBy";
 import { createTransferInstruction } from "../../sdk/generated/instructions/Transfer";
@@ -87,13 +91,22 @@ test("Set in use by", async () => {
   );
 
   const tx = new Transaction();
-  const ix = createSetInUseByInstruction({
-    mintManager: mintManagerId,
-    ruleset: RULESET_ID,
-    inUseByAddress: inUseByAddress.publicKey,
-    holder: provider.wallet.publicKey,
-    holderTokenAccount: holderAtaId,
-  });
+  const ix = createSetInUseByInstruction(
+    {
+      mintManager: mintManagerId,
+      ruleset: RULESET_ID,
+      inUseByAddress: inUseByAddress.publicKey,
+      holder: provider.wallet.publicKey,
+      holderTokenAccount: holderAtaId,
+    },
+    {
+      setInUseByIx: {
+        inUseUntil: null,
+        purpose: InUsePurpose.Unspecified,
+        reference: null,
+      },
+    },
+  );
   handleRemainingAccountsForRuleset(ix, rulesetData);
   tx.add(ix);
   await executeTransaction(provider.connection, tx, provider.wallet);
@@ -142,16 +155,19 @@ test("Transfer", async () => {
       mintKeypair.publicKey,
     ),
   );
-  const ix = createTransferInstruction({
-    mintManager: mintManagerId,
-    mintMetadata: mintMetadataId,
-    ruleset: RULESET_ID,
-    mint: mintKeypair.publicKey,
-    from: fromAtaId,
-    to: toAtaId,
-    authority: provider.wallet.publicKey,
-    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-  });
+  const ix = createTransferInstruction(
+    {
+      mintManager: mintManagerId,
+      mintMetadata: mintMetadataId,
+      ruleset: RULESET_ID,
+      mint: mintKeypair.publicKey,
+      from: fromAtaId,
+      to: toAtaId,
+      authority: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    },
+    { transferIx: { amount: 1 } },
+  );
   handleRemainingAccountsForRuleset(ix, rulesetData);
   tx.add(ix);
   await expect(
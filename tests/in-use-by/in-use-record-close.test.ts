@@ -0,0 +1,248 @@
+import { beforeAll, describe, expect, test } from "@jest/globals";
+import {
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import {
+  createCloseInstruction,
+  createCloseMintManagerInstruction,
+  createInitMintManagerInstruction,
+  handleRemainingAccountsForRuleset,
+  MintManagerKind,
+  Ruleset,
+} from "../../sdk";
+import { createBurnInstruction } from "../../sdk/generated/instructions/Burn";
+import { createInitInUseRecordInstruction } from "../../sdk/generated/instructions/InitInUseRecord";
+import { createTransferInstruction } from "../../sdk/generated/instructions/Transfer";
+import {
+  findInUseRecordId,
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const RULESET_ID = findRulesetId();
+
+describe.each([MintManagerKind.SemiFungible, MintManagerKind.Fungible])(
+  "Close with in use records for mint manager kind %s",
+  (kind) => {
+    const mintKeypair = Keypair.generate();
+    const emptyHolder = Keypair.generate();
+    const recipient = Keypair.generate();
+    let provider: SolanaProvider;
+    let holderAtaId: PublicKey;
+    let emptyHolderAtaId: PublicKey;
+    let recipientAtaId: PublicKey;
+
+    const inUseRecordKey = (tokenAccount: PublicKey) => ({
+      pubkey: findInUseRecordId(tokenAccount),
+      isWritable: true,
+      isSigner: false,
+    });
+
+    const closeTx = (withInUseRecord: boolean) => {
+      const ix = createCloseInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        tokenAccount: emptyHolderAtaId,
+        owner: emptyHolder.publicKey,
+      });
+      if (withInUseRecord) {
+        ix.keys.push(inUseRecordKey(emptyHolderAtaId));
+      }
+      return new Transaction().add(ix);
+    };
+
+    const closeMintManagerTx = () => {
+      const ix = createCloseMintManagerInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        holderTokenAccount: holderAtaId,
+        newTokenAuthority: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+      });
+      ix.keys.push(inUseRecordKey(holderAtaId));
+      return new Transaction().add(ix);
+    };
+
+    beforeAll(async () => {
+      provider = await getProvider();
+      holderAtaId = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        provider.wallet.publicKey,
+      );
+      emptyHolderAtaId = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        emptyHolder.publicKey,
+      );
+      recipientAtaId = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        recipient.publicKey,
+      );
+      const tx = await createMintTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey,
+      );
+      tx.add(
+        createMintToInstruction(
+          mintKeypair.publicKey,
+          holderAtaId,
+          provider.wallet.publicKey,
+          4,
+        ),
+        createMintMetadataTx(mintKeypair.publicKey, provider.wallet.publicKey),
+        createInitMintManagerInstruction(
+          {
+            mintManager: findMintManagerId(mintKeypair.publicKey),
+            mint: mintKeypair.publicKey,
+            mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+            ruleset: RULESET_ID,
+            holderTokenAccount: holderAtaId,
+            tokenAuthority: provider.wallet.publicKey,
+            authority: provider.wallet.publicKey,
+            payer: provider.wallet.publicKey,
+          },
+          { initMintManagerIx: { kind } },
+        ),
+      );
+      await executeTransaction(provider.connection, tx, provider.wallet, [
+        mintKeypair,
+      ]);
+
+      const setupTx = new Transaction();
+      for (const [owner, ataId] of [
+        [emptyHolder.publicKey, emptyHolderAtaId],
+        [recipient.publicKey, recipientAtaId],
+      ] as const) {
+        setupTx.add(
+          createAssociatedTokenAccountInstruction(
+            provider.wallet.publicKey,
+            ataId,
+            owner,
+            mintKeypair.publicKey,
+          ),
+        );
+      }
+      for (const ataId of [holderAtaId, emptyHolderAtaId]) {
+        setupTx.add(
+          createInitInUseRecordInstruction({
+            inUseRecord: findInUseRecordId(ataId),
+            mintManager: findMintManagerId(mintKeypair.publicKey),
+            holderTokenAccount: ataId,
+            payer: provider.wallet.publicKey,
+          }),
+        );
+      }
+      await executeTransaction(provider.connection, setupTx, provider.wallet);
+    });
+
+    test("Fail to close a token account without its in use record", async () => {
+      await expect(
+        executeTransaction(
+          provider.connection,
+          closeTx(false),
+          provider.wallet,
+          [emptyHolder],
+        ),
+      ).rejects.toThrow();
+    });
+
+    test("Close a token account with its in use record", async () => {
+      await executeTransaction(
+        provider.connection,
+        closeTx(true),
+        provider.wallet,
+        [emptyHolder],
+      );
+      const inUseRecord = await provider.connection.getAccountInfo(
+        findInUseRecordId(emptyHolderAtaId),
+      );
+      expect(inUseRecord).toBeNull();
+    });
+
+    test("Fail to close the mint manager while others hold tokens", async () => {
+      const rulesetData = await Ruleset.fromAccountAddress(
+        provider.connection,
+        RULESET_ID,
+      );
+      const ix = createTransferInstruction(
+        {
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          mint: mintKeypair.publicKey,
+          mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+          ruleset: RULESET_ID,
+          from: holderAtaId,
+          to: recipientAtaId,
+          authority: provider.wallet.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        { transferIx: { amount: 1 } },
+      );
+      ix.keys.push(inUseRecordKey(holderAtaId));
+      handleRemainingAccountsForRuleset(ix, rulesetData);
+      await executeTransaction(
+        provider.connection,
+        new Transaction().add(ix),
+        provider.wallet,
+      );
+
+      await expect(
+        executeTransaction(
+          provider.connection,
+          closeMintManagerTx(),
+          provider.wallet,
+        ),
+      ).rejects.toThrow();
+    });
+
+    test("Close the mint manager once the holder has the entire supply", async () => {
+      const burnIx = createBurnInstruction(
+        {
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          mint: mintKeypair.publicKey,
+          holderTokenAccount: recipientAtaId,
+          holder: recipient.publicKey,
+        },
+        { burnIx: { amount: 1 } },
+      );
+      burnIx.keys.push(inUseRecordKey(recipientAtaId));
+      await executeTransaction(
+        provider.connection,
+        new Transaction().add(burnIx),
+        provider.wallet,
+        [recipient],
+      );
+
+      await executeTransaction(
+        provider.connection,
+        closeMintManagerTx(),
+        provider.wallet,
+      );
+      const mintManager = await provider.connection.getAccountInfo(
+        findMintManagerId(mintKeypair.publicKey),
+      );
+      expect(mintManager).toBeNull();
+      const inUseRecord = await provider.connection.getAccountInfo(
+        findInUseRecordId(holderAtaId),
+      );
+      expect(inUseRecord).toBeNull();
+    });
+  },
+);
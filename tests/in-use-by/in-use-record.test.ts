@@ -0,0 +1,219 @@
+import { beforeAll, describe, expect, test } from "@jest/globals";
+import {
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import {
+  createInitMintManagerInstruction,
+  handleRemainingAccountsForRuleset,
+  InUseRecord,
+  MintManagerKind,
+  Ruleset,
+} from "../../sdk";
+import { createBurnInstruction } from "../../sdk/generated/instructions/Burn";
+import { createInitInUseRecordInstruction } from "../../sdk/generated/instructions/InitInUseRecord";
+import { createTransferInstruction } from "../../sdk/generated/instructions/Transfer";
+import {
+  findInUseRecordId,
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  executeTransaction,
+  getProvider,
+  tryGetAccount,
+} from "../../utils";
+
+const RULESET_ID = findRulesetId();
+
+describe.each([MintManagerKind.SemiFungible, MintManagerKind.Fungible])(
+  "In use record for mint manager kind %s",
+  (kind) => {
+    const mintKeypair = Keypair.generate();
+    const recipient = Keypair.generate();
+    let provider: SolanaProvider;
+    let holderAtaId: PublicKey;
+    let recipientAtaId: PublicKey;
+
+    const transferTx = async (amount: number, withInUseRecord = true) => {
+      const rulesetData = await Ruleset.fromAccountAddress(
+        provider.connection,
+        RULESET_ID,
+      );
+      const ix = createTransferInstruction(
+        {
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          mint: mintKeypair.publicKey,
+          mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+          ruleset: RULESET_ID,
+          from: holderAtaId,
+          to: recipientAtaId,
+          authority: provider.wallet.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        { transferIx: { amount } },
+      );
+      if (withInUseRecord) {
+        ix.keys.push({
+          pubkey: findInUseRecordId(holderAtaId),
+          isWritable: true,
+          isSigner: false,
+        });
+      }
+      handleRemainingAccountsForRuleset(ix, rulesetData);
+      return new Transaction().add(ix);
+    };
+
+    const burnTx = (amount: number) => {
+      const ix = createBurnInstruction(
+        {
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          mint: mintKeypair.publicKey,
+          holderTokenAccount: holderAtaId,
+          holder: provider.wallet.publicKey,
+        },
+        { burnIx: { amount } },
+      );
+      ix.keys.push({
+        pubkey: findInUseRecordId(holderAtaId),
+        isWritable: true,
+        isSigner: false,
+      });
+      return new Transaction().add(ix);
+    };
+
+    beforeAll(async () => {
+      provider = await getProvider();
+      holderAtaId = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        provider.wallet.publicKey,
+      );
+      recipientAtaId = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        recipient.publicKey,
+      );
+      const tx = await createMintTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey,
+      );
+      tx.add(
+        createMintToInstruction(
+          mintKeypair.publicKey,
+          holderAtaId,
+          provider.wallet.publicKey,
+          4,
+        ),
+        createMintMetadataTx(mintKeypair.publicKey, provider.wallet.publicKey),
+        createInitMintManagerInstruction(
+          {
+            mintManager: findMintManagerId(mintKeypair.publicKey),
+            mint: mintKeypair.publicKey,
+            mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+            ruleset: RULESET_ID,
+            holderTokenAccount: holderAtaId,
+            tokenAuthority: provider.wallet.publicKey,
+            authority: provider.wallet.publicKey,
+            payer: provider.wallet.publicKey,
+          },
+          { initMintManagerIx: { kind } },
+        ),
+        createAssociatedTokenAccountInstruction(
+          provider.wallet.publicKey,
+          recipientAtaId,
+          recipient.publicKey,
+          mintKeypair.publicKey,
+        ),
+      );
+      await executeTransaction(provider.connection, tx, provider.wallet, [
+        mintKeypair,
+      ]);
+    });
+
+    test("Init in use record", async () => {
+      const tx = new Transaction().add(
+        createInitInUseRecordInstruction({
+          inUseRecord: findInUseRecordId(holderAtaId),
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          holderTokenAccount: holderAtaId,
+          payer: provider.wallet.publicKey,
+        }),
+      );
+      await executeTransaction(provider.connection, tx, provider.wallet);
+
+      const inUseRecord = await InUseRecord.fromAccountAddress(
+        provider.connection,
+        findInUseRecordId(holderAtaId),
+      );
+      expect(inUseRecord.mint.toString()).toBe(
+        mintKeypair.publicKey.toString(),
+      );
+      expect(inUseRecord.holderTokenAccount.toString()).toBe(
+        holderAtaId.toString(),
+      );
+      expect(inUseRecord.inUseBy).toBeNull();
+    });
+
+    test("Fail to transfer without in use record", async () => {
+      await expect(
+        executeTransaction(
+          provider.connection,
+          await transferTx(1, false),
+          provider.wallet,
+        ),
+      ).rejects.toThrow();
+    });
+
+    test("Transfer part of the balance", async () => {
+      await executeTransaction(
+        provider.connection,
+        await transferTx(2),
+        provider.wallet,
+      );
+
+      const holderAta = await getAccount(provider.connection, holderAtaId);
+      expect(holderAta.amount.toString()).toBe("3");
+      expect(holderAta.isFrozen).toBe(true);
+      const recipientAta = await getAccount(
+        provider.connection,
+        recipientAtaId,
+      );
+      expect(recipientAta.amount.toString()).toBe("2");
+      expect(recipientAta.isFrozen).toBe(true);
+    });
+
+    test("Burn part of the balance", async () => {
+      await executeTransaction(provider.connection, burnTx(1), provider.wallet);
+
+      const holderAta = await getAccount(provider.connection, holderAtaId);
+      expect(holderAta.amount.toString()).toBe("2");
+      expect(holderAta.isFrozen).toBe(true);
+    });
+
+    test("Burn the remaining balance", async () => {
+      await executeTransaction(provider.connection, burnTx(2), provider.wallet);
+
+      const holderAta = await tryGetAccount(() =>
+        getAccount(provider.connection, holderAtaId),
+      );
+      expect(holderAta).toBeNull();
+      const inUseRecord = await provider.connection.getAccountInfo(
+        findInUseRecordId(holderAtaId),
+      );
+      expect(inUseRecord).toBeNull();
+    });
+  },
+);
@@ -0,0 +1,142 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import {
+  createForceRemoveInUseByInstruction,
+  createRenewInUseByInstruction,
+  createRequestInUseReleaseInstruction,
+  createSetInUseByInstruction,
+  InUsePurpose,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import { findMintManagerId, findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const GRACE_PERIOD_SECONDS = 2;
+const mintKeypair = Keypair.generate();
+const inUseByAddress = Keypair.generate();
+
+let provider: SolanaProvider;
+
+const holderAccounts = () => ({
+  mintManager: findMintManagerId(mintKeypair.publicKey),
+  ruleset: findRulesetId(RULESET_NAME),
+  inUseByAddress: inUseByAddress.publicKey,
+  holder: provider.wallet.publicKey,
+  holderTokenAccount: getAssociatedTokenAddressSync(
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+  ),
+});
+
+const requestInUseReleaseTx = () =>
+  new Transaction().add(createRequestInUseReleaseInstruction(holderAccounts()));
+
+const forceRemoveInUseByTx = () =>
+  new Transaction().add(createForceRemoveInUseByInstruction(holderAccounts()));
+
+const getMintManager = () =>
+  MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+
+beforeAll(async () => {
+  provider = await getProvider();
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      inUseReleaseGracePeriodSeconds: GRACE_PERIOD_SECONDS,
+    }),
+    provider.wallet,
+  );
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    findRulesetId(RULESET_NAME),
+  );
+  tx.add(
+    createSetInUseByInstruction(holderAccounts(), {
+      setInUseByIx: {
+        inUseUntil: null,
+        purpose: InUsePurpose.Staked,
+        reference: null,
+      },
+    }),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+test("Request in use release", async () => {
+  await executeTransaction(
+    provider.connection,
+    requestInUseReleaseTx(),
+    provider.wallet,
+  );
+  const mintManager = await getMintManager();
+  expect(mintManager.inUseReleaseRequestedAt).not.toBeNull();
+});
+
+test("Fail to force remove during the grace period", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      forceRemoveInUseByTx(),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Renew in use by", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createRenewInUseByInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        user: inUseByAddress.publicKey,
+      }),
+    ),
+    provider.wallet,
+    [inUseByAddress],
+  );
+  const mintManager = await getMintManager();
+  expect(mintManager.inUseReleaseRequestedAt).toBeNull();
+
+  // the renewal cleared the request so there is nothing to force
+  await expect(
+    executeTransaction(
+      provider.connection,
+      forceRemoveInUseByTx(),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Force remove in use by after the grace period", async () => {
+  await executeTransaction(
+    provider.connection,
+    requestInUseReleaseTx(),
+    provider.wallet,
+  );
+  await new Promise((resolve) =>
+    setTimeout(resolve, (GRACE_PERIOD_SECONDS + 1) * 1000),
+  );
+  await executeTransaction(
+    provider.connection,
+    forceRemoveInUseByTx(),
+    provider.wallet,
+  );
+  const mintManager = await getMintManager();
+  expect(mintManager.inUseBy).toBeNull();
+});
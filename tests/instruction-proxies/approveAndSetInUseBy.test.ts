@@ -9,6 +9,7 @@ import { Keypair, Transaction } from "@solana/web3.js";
 import {
   createApproveAndSetInUseByInstruction,
   handleRemainingAccountsForRuleset,
+  InUsePurpose,
   Ruleset,
 } from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
@@ -100,6 +101,9 @@ test("Delegate and set in_use_by", async () => {
     {
       approveAndSetInUseByIx: {
         amount: 1,
+        inUseUntil: null,
+        purpose: InUsePurpose.Unspecified,
+        reference: null,
       },
     },
   );
@@ -76,12 +76,15 @@ test("Burn mint", async () => {
   expect(holderAta.amount.toString()).toBe("1");
 
   tx.add(
-    createBurnInstruction({
-      mintManager: mintManagerId,
-      mint: mintKeypair.publicKey,
-      holderTokenAccount: holderAtaId,
-      holder: provider.wallet.publicKey,
-    }),
+    createBurnInstruction(
+      {
+        mintManager: mintManagerId,
+        mint: mintKeypair.publicKey,
+        holderTokenAccount: holderAtaId,
+        holder: provider.wallet.publicKey,
+      },
+      { burnIx: { amount: 1 } },
+    ),
   );
   await executeTransaction(provider.connection, tx, provider.wallet);
   const mintInfo = await tryGetAccount(() =>
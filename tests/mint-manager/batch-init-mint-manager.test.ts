@@ -0,0 +1,101 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import { createBatchInitMintManagerInstruction } from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const mintKeypairs = [Keypair.generate(), Keypair.generate()];
+
+const RULESET_ID = findRulesetId();
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  for (const mintKeypair of mintKeypairs) {
+    const tx = await createMintTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey,
+    );
+    tx.add(
+      createMintMetadataTx(mintKeypair.publicKey, provider.wallet.publicKey),
+    );
+    await executeTransaction(provider.connection, tx, provider.wallet, [
+      mintKeypair,
+    ]);
+  }
+});
+
+const batchInitMintManagerIx = () =>
+  createBatchInitMintManagerInstruction({
+    ruleset: RULESET_ID,
+    tokenAuthority: provider.wallet.publicKey,
+    authority: provider.wallet.publicKey,
+    payer: provider.wallet.publicKey,
+  });
+
+test("Fail to batch init without any mints", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(batchInitMintManagerIx()),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Batch init", async () => {
+  const ix = batchInitMintManagerIx();
+  // (mint_manager, mint, mint_metadata, holder_token_account) for each mint
+  for (const mintKeypair of mintKeypairs) {
+    ix.keys.push(
+      {
+        pubkey: findMintManagerId(mintKeypair.publicKey),
+        isWritable: true,
+        isSigner: false,
+      },
+      { pubkey: mintKeypair.publicKey, isWritable: true, isSigner: false },
+      {
+        pubkey: findMintMetadataId(mintKeypair.publicKey),
+        isWritable: false,
+        isSigner: false,
+      },
+      {
+        pubkey: getAssociatedTokenAddressSync(
+          mintKeypair.publicKey,
+          provider.wallet.publicKey,
+        ),
+        isWritable: true,
+        isSigner: false,
+      },
+    );
+  }
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet,
+  );
+
+  for (const mintKeypair of mintKeypairs) {
+    const mintManager = await MintManager.fromAccountAddress(
+      provider.connection,
+      findMintManagerId(mintKeypair.publicKey),
+    );
+    expect(mintManager.mint.toString()).toBe(mintKeypair.publicKey.toString());
+    expect(mintManager.ruleset.toString()).toBe(RULESET_ID.toString());
+  }
+});
@@ -0,0 +1,62 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import { createMigrateMintManagerInstruction } from "../../sdk/generated/instructions/MigrateMintManager";
+import { findMintManagerId, findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const mintKeypair = Keypair.generate();
+
+const RULESET_ID = findRulesetId();
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    RULESET_ID,
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+test("Migrate mint manager", async () => {
+  const mintManagerId = findMintManagerId(mintKeypair.publicKey);
+  const tx = new Transaction().add(
+    createMigrateMintManagerInstruction({
+      mintManager: mintManagerId,
+      payer: provider.wallet.publicKey,
+    }),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    mintManagerId,
+  );
+  expect(mintManager.version).toBe(1);
+  expect(mintManager.mint.toString()).toBe(mintKeypair.publicKey.toString());
+  expect(mintManager.ruleset.toString()).toBe(RULESET_ID.toString());
+});
+
+test("Fail to migrate an account that is not a mint manager", async () => {
+  const tx = new Transaction().add(
+    createMigrateMintManagerInstruction({
+      mintManager: RULESET_ID,
+      payer: provider.wallet.publicKey,
+    }),
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet),
+  ).rejects.toThrow();
+});
@@ -5,6 +5,7 @@ import { Keypair, Transaction } from "@solana/web3.js";
 import {
   createCloseMintManagerInstruction,
   createInitMintManagerInstruction,
+  MintManagerKind,
 } from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import {
@@ -13,7 +14,12 @@ import {
   findRulesetId,
 } from "../../sdk/pda";
 import type { SolanaProvider } from "../../utils";
-import { createMintTx, executeTransaction, getProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
 
 const mintKeypair = Keypair.generate();
 const RULESET_ID_1 = findRulesetId();
@@ -26,6 +32,9 @@ beforeAll(async () => {
     mintKeypair.publicKey,
     provider.wallet.publicKey,
   );
+  splMintIx.add(
+    createMintMetadataTx(mintKeypair.publicKey, provider.wallet.publicKey),
+  );
   await executeTransaction(provider.connection, splMintIx, provider.wallet, [
     mintKeypair,
   ]);
@@ -41,16 +50,19 @@ test("Init mint manager", async () => {
     provider.wallet.publicKey,
   );
   tx.add(
-    createInitMintManagerInstruction({
-      mintManager: mintManagerId,
-      mint: mintKeypair.publicKey,
-      mintMetadata: mintMetadataId,
-      ruleset: RULESET_ID_1,
-      holderTokenAccount: ata,
-      tokenAuthority: provider.wallet.publicKey,
-      authority: provider.wallet.publicKey,
-      payer: provider.wallet.publicKey,
-    }),
+    createInitMintManagerInstruction(
+      {
+        mintManager: mintManagerId,
+        mint: mintKeypair.publicKey,
+        mintMetadata: mintMetadataId,
+        ruleset: RULESET_ID_1,
+        holderTokenAccount: ata,
+        tokenAuthority: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+      },
+      { initMintManagerIx: { kind: MintManagerKind.NonFungible } },
+    ),
   );
   await executeTransaction(provider.connection, tx, provider.wallet);
 
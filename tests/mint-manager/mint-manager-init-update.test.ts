@@ -2,7 +2,7 @@ import { beforeAll, expect, test } from "@jest/globals";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { Keypair, Transaction } from "@solana/web3.js";
 
-import { createInitMintManagerInstruction } from "../../sdk";
+import { createInitMintManagerInstruction, MintManagerKind } from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import { createUpdateMintManagerInstruction } from "../../sdk/generated/instructions/UpdateMintManager";
 import {
@@ -11,7 +11,12 @@ import {
   findRulesetId,
 } from "../../sdk/pda";
 import type { SolanaProvider } from "../../utils";
-import { createMintTx, executeTransaction, getProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
 
 const mintKeypair = Keypair.generate();
 const RULESET_NAME_2 = "ruleset-no-checks-2";
@@ -26,6 +31,9 @@ beforeAll(async () => {
     mintKeypair.publicKey,
     provider.wallet.publicKey,
   );
+  splMintIx.add(
+    createMintMetadataTx(mintKeypair.publicKey, provider.wallet.publicKey),
+  );
   await executeTransaction(provider.connection, splMintIx, provider.wallet, [
     mintKeypair,
   ]);
@@ -41,16 +49,19 @@ test("Init mint manager", async () => {
     provider.wallet.publicKey,
   );
   tx.add(
-    createInitMintManagerInstruction({
-      mintManager: mintManagerId,
-      mint: mintKeypair.publicKey,
-      mintMetadata: mintMetadataId,
-      ruleset: RULESET_ID_1,
-      holderTokenAccount: ata,
-      tokenAuthority: provider.wallet.publicKey,
-      authority: provider.wallet.publicKey,
-      payer: provider.wallet.publicKey,
-    }),
+    createInitMintManagerInstruction(
+      {
+        mintManager: mintManagerId,
+        mint: mintKeypair.publicKey,
+        mintMetadata: mintMetadataId,
+        ruleset: RULESET_ID_1,
+        holderTokenAccount: ata,
+        tokenAuthority: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+      },
+      { initMintManagerIx: { kind: MintManagerKind.NonFungible } },
+    ),
   );
   await executeTransaction(provider.connection, tx, provider.wallet);
 
@@ -75,6 +86,7 @@ test("Update mint manager", async () => {
     createUpdateMintManagerInstruction(
       {
         mintManager: mintManagerId,
+        mintMetadata: findMintMetadataId(mintKeypair.publicKey),
         authority: provider.wallet.publicKey,
         payer: provider.wallet.publicKey,
         ruleset: RULESET_ID_2,
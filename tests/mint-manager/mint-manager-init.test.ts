@@ -2,7 +2,7 @@ import { beforeAll, expect, test } from "@jest/globals";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { Keypair, Transaction } from "@solana/web3.js";
 
-import { createInitMintManagerInstruction } from "../../sdk";
+import { createInitMintManagerInstruction, MintManagerKind } from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import {
   findMintManagerId,
@@ -10,7 +10,12 @@ import {
   findRulesetId,
 } from "../../sdk/pda";
 import type { SolanaProvider } from "../../utils";
-import { createMintTx, executeTransaction, getProvider } from "../../utils";
+import {
+  createMintMetadataTx,
+  createMintTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
 
 const mintKeypair = Keypair.generate();
 
@@ -25,6 +30,9 @@ beforeAll(async () => {
     mintKeypair.publicKey,
     provider.wallet.publicKey,
   );
+  splMintIx.add(
+    createMintMetadataTx(mintKeypair.publicKey, provider.wallet.publicKey),
+  );
   await executeTransaction(provider.connection, splMintIx, provider.wallet, [
     mintKeypair,
   ]);
@@ -39,16 +47,19 @@ test("Init", async () => {
     provider.wallet.publicKey,
   );
   tx.add(
-    createInitMintManagerInstruction({
-      mintManager: mintManagerId,
-      mint: mintKeypair.publicKey,
-      mintMetadata: findMintMetadataId(mintKeypair.publicKey),
-      ruleset: RULESET_ID,
-      holderTokenAccount: ata,
-      tokenAuthority: provider.wallet.publicKey,
-      authority: provider.wallet.publicKey,
-      payer: provider.wallet.publicKey,
-    }),
+    createInitMintManagerInstruction(
+      {
+        mintManager: mintManagerId,
+        mint: mintKeypair.publicKey,
+        mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+        ruleset: RULESET_ID,
+        holderTokenAccount: ata,
+        tokenAuthority: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+      },
+      { initMintManagerIx: { kind: MintManagerKind.NonFungible } },
+    ),
   );
   await executeTransaction(provider.connection, tx, provider.wallet);
 
@@ -63,3 +74,46 @@ test("Init", async () => {
   );
   expect(mintManager.ruleset.toString()).toBe(RULESET_ID.toString());
 });
+
+test("Fail to init with a token authority that is not an update authority or verified creator", async () => {
+  const otherMintKeypair = Keypair.generate();
+  const tokenAuthority = Keypair.generate();
+  const tx = await createMintTx(
+    provider.connection,
+    otherMintKeypair.publicKey,
+    provider.wallet.publicKey,
+  );
+  tx.add(
+    createMintMetadataTx(otherMintKeypair.publicKey, provider.wallet.publicKey),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    otherMintKeypair,
+  ]);
+
+  const ata = getAssociatedTokenAddressSync(
+    otherMintKeypair.publicKey,
+    provider.wallet.publicKey,
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createInitMintManagerInstruction(
+          {
+            mintManager: findMintManagerId(otherMintKeypair.publicKey),
+            mint: otherMintKeypair.publicKey,
+            mintMetadata: findMintMetadataId(otherMintKeypair.publicKey),
+            ruleset: RULESET_ID,
+            holderTokenAccount: ata,
+            tokenAuthority: tokenAuthority.publicKey,
+            authority: provider.wallet.publicKey,
+            payer: provider.wallet.publicKey,
+          },
+          { initMintManagerIx: { kind: MintManagerKind.NonFungible } },
+        ),
+      ),
+      provider.wallet,
+      [tokenAuthority],
+    ),
+  ).rejects.toThrow();
+});
@@ -0,0 +1,131 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+} from "@solana/spl-token";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import { createMintNewTokenInstruction } from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+  METADATA_PROGRAM_ID,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createRulesetTx, executeTransaction, getProvider } from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+
+let provider: SolanaProvider;
+
+const mintNewTokenTx = (
+  mint: Keypair,
+  permanentDelegate: boolean,
+  sellerFeeBasisPoints = 500,
+): Transaction =>
+  new Transaction().add(
+    createMintNewTokenInstruction(
+      {
+        mintManager: findMintManagerId(mint.publicKey),
+        mint: mint.publicKey,
+        mintMetadata: findMintMetadataId(mint.publicKey),
+        ruleset: findRulesetId(RULESET_NAME),
+        targetTokenAccount: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          provider.wallet.publicKey,
+        ),
+        target: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+      },
+      {
+        mintNewTokenIx: {
+          name: "TEST",
+          symbol: "TST",
+          uri: "",
+          sellerFeeBasisPoints,
+          permanentDelegate,
+        },
+      },
+    ),
+  );
+
+beforeAll(async () => {
+  provider = await getProvider();
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      minimumCreatorBasisPoints: 100,
+    }),
+    provider.wallet,
+  );
+});
+
+test("Mint new token with metadata", async () => {
+  const mintKeypair = Keypair.generate();
+  await executeTransaction(
+    provider.connection,
+    mintNewTokenTx(mintKeypair, false),
+    provider.wallet,
+    [mintKeypair],
+  );
+
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.authority.toString()).toBe(
+    provider.wallet.publicKey.toString(),
+  );
+  expect(mintManager.ruleset.toString()).toBe(
+    findRulesetId(RULESET_NAME).toString(),
+  );
+
+  const mintMetadataInfo = await provider.connection.getAccountInfo(
+    findMintMetadataId(mintKeypair.publicKey),
+  );
+  expect(mintMetadataInfo?.owner.toString()).toBe(
+    METADATA_PROGRAM_ID.toString(),
+  );
+
+  const mint = await getMint(provider.connection, mintKeypair.publicKey);
+  expect(mint.supply.toString()).toBe("1");
+  const targetTokenAccount = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(
+      mintKeypair.publicKey,
+      provider.wallet.publicKey,
+    ),
+  );
+  expect(targetTokenAccount.isFrozen).toBe(true);
+});
+
+test("Fail to mint below the ruleset minimum creator basis points", async () => {
+  const mintKeypair = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      mintNewTokenTx(mintKeypair, false, 50),
+      provider.wallet,
+      [mintKeypair],
+    ),
+  ).rejects.toThrow();
+});
+
+test("Fail to mint with a permanent delegate on the legacy token program", async () => {
+  const mintKeypair = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      mintNewTokenTx(mintKeypair, true),
+      provider.wallet,
+      [mintKeypair],
+    ),
+  ).rejects.toThrow();
+});
@@ -0,0 +1,173 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import {
+  createDisableRecallInstruction,
+  createMintNewTokenInstruction,
+  createRecallInstruction,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+  METADATA_PROGRAM_ID,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createRulesetTx, executeTransaction, getProvider } from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const mintKeypair = Keypair.generate();
+const recipient = Keypair.generate();
+
+let provider: SolanaProvider;
+
+const ataFor = (owner: PublicKey) =>
+  getAssociatedTokenAddressSync(
+    mintKeypair.publicKey,
+    owner,
+    true,
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+const recallTx = (
+  from: PublicKey,
+  to: PublicKey,
+  authority = provider.wallet.publicKey,
+) =>
+  new Transaction().add(
+    createRecallInstruction(
+      {
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        ruleset: findRulesetId(RULESET_NAME),
+        from: ataFor(from),
+        to: ataFor(to),
+        authority,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      },
+      { recallIx: { amount: 1 } },
+    ),
+  );
+
+beforeAll(async () => {
+  provider = await getProvider();
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      allowRecall: true,
+    }),
+    provider.wallet,
+  );
+
+  const tx = new Transaction().add(
+    createMintNewTokenInstruction(
+      {
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+        ruleset: findRulesetId(RULESET_NAME),
+        targetTokenAccount: ataFor(recipient.publicKey),
+        target: recipient.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+      },
+      {
+        mintNewTokenIx: {
+          name: "TEST",
+          symbol: "TST",
+          uri: "",
+          sellerFeeBasisPoints: 500,
+          permanentDelegate: true,
+        },
+      },
+    ),
+    createAssociatedTokenAccountInstruction(
+      provider.wallet.publicKey,
+      ataFor(provider.wallet.publicKey),
+      provider.wallet.publicKey,
+      mintKeypair.publicKey,
+      TOKEN_2022_PROGRAM_ID,
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+test("Fail to recall without the mint manager authority", async () => {
+  const otherAuthority = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      recallTx(
+        recipient.publicKey,
+        provider.wallet.publicKey,
+        otherAuthority.publicKey,
+      ),
+      provider.wallet,
+      [otherAuthority],
+    ),
+  ).rejects.toThrow();
+});
+
+test("Recall with the permanent delegate", async () => {
+  await executeTransaction(
+    provider.connection,
+    recallTx(recipient.publicKey, provider.wallet.publicKey),
+    provider.wallet,
+  );
+
+  const recalledFrom = await getAccount(
+    provider.connection,
+    ataFor(recipient.publicKey),
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  expect(recalledFrom.amount.toString()).toBe("0");
+  const recalledTo = await getAccount(
+    provider.connection,
+    ataFor(provider.wallet.publicKey),
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  expect(recalledTo.amount.toString()).toBe("1");
+  expect(recalledTo.isFrozen).toBe(true);
+});
+
+test("Disable recall", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createDisableRecallInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        authority: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.recallDisabled).toBe(true);
+
+  await expect(
+    executeTransaction(
+      provider.connection,
+      recallTx(provider.wallet.publicKey, recipient.publicKey),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
@@ -0,0 +1,139 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+
+import {
+  createMigrateMintManagerInstruction,
+  createRenounceMintManagerAuthorityInstruction,
+  createUpdateMintManagerInstruction,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createCCSMintTx, executeTransaction, getProvider } from "../../utils";
+
+const mintKeypair = Keypair.generate();
+
+const RULESET_ID = findRulesetId();
+
+// version 0 mint manager loaded from tests/fixtures/legacy-mint-manager-renounce.json
+const LEGACY_MINT = new PublicKey(
+  "DVdHbog1WgFYrBvJndPRa116HoQiiz2oRJV8K37XncEW",
+);
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    RULESET_ID,
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+test("Fail to renounce without the mint manager authority", async () => {
+  const otherAuthority = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createRenounceMintManagerAuthorityInstruction({
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          authority: otherAuthority.publicKey,
+        }),
+      ),
+      provider.wallet,
+      [otherAuthority],
+    ),
+  ).rejects.toThrow();
+});
+
+test("Renounce authority", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createRenounceMintManagerAuthorityInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        authority: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.authorityRenounced).toBe(true);
+  expect(mintManager.ruleset.toString()).toBe(RULESET_ID.toString());
+});
+
+test("Fail to update a renounced mint manager", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createUpdateMintManagerInstruction(
+          {
+            mintManager: findMintManagerId(mintKeypair.publicKey),
+            mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+            authority: provider.wallet.publicKey,
+            payer: provider.wallet.publicKey,
+            ruleset: RULESET_ID,
+          },
+          {
+            updateMintManagerIx: {
+              authority: Keypair.generate().publicKey,
+            },
+          },
+        ),
+      ),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Fail to renounce an unmigrated mint manager", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createRenounceMintManagerAuthorityInstruction({
+          mintManager: findMintManagerId(LEGACY_MINT),
+          authority: provider.wallet.publicKey,
+        }),
+      ),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Renounce authority after migration", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createMigrateMintManagerInstruction({
+        mintManager: findMintManagerId(LEGACY_MINT),
+        payer: provider.wallet.publicKey,
+      }),
+      createRenounceMintManagerAuthorityInstruction({
+        mintManager: findMintManagerId(LEGACY_MINT),
+        authority: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(LEGACY_MINT),
+  );
+  expect(mintManager.version).toBe(1);
+  expect(mintManager.authorityRenounced).toBe(true);
+});
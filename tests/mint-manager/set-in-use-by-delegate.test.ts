@@ -0,0 +1,120 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Wallet } from "@project-serum/anchor";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import {
+  handleRemainingAccountsForRuleset,
+  InUsePurpose,
+  Ruleset,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import { createApproveInstruction } from "../../sdk/generated/instructions/Approve";
+import { createSetInUseByInstruction } from "../../sdk/generated/instructions/SetInUseBy";
+import { findMintManagerId, findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  executeTransaction,
+  getProvider,
+  newAccountWithLamports,
+} from "../../utils";
+
+const mintKeypair = Keypair.generate();
+
+const RULESET_ID = findRulesetId();
+const inUseByAddress = Keypair.generate();
+
+let provider: SolanaProvider;
+let delegate: Keypair;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  delegate = await newAccountWithLamports(provider.connection);
+});
+
+test("Initialize mint", async () => {
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    RULESET_ID,
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+test("Delegate", async () => {
+  const rulesetData = await Ruleset.fromAccountAddress(
+    provider.connection,
+    RULESET_ID,
+  );
+  const mintManagerId = findMintManagerId(mintKeypair.publicKey);
+  const holderAtaId = getAssociatedTokenAddressSync(
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+  );
+
+  const tx = new Transaction();
+  const ix = createApproveInstruction(
+    {
+      mintManager: mintManagerId,
+      ruleset: RULESET_ID,
+      mint: mintKeypair.publicKey,
+      holderTokenAccount: holderAtaId,
+      holder: provider.wallet.publicKey,
+      delegate: delegate.publicKey,
+    },
+    { approveIx: { amount: 1 } },
+  );
+  handleRemainingAccountsForRuleset(ix, rulesetData);
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const holderAta = await getAccount(provider.connection, holderAtaId);
+  expect(holderAta.delegate?.toString()).toBe(delegate.publicKey.toString());
+});
+
+test("Set in use by as delegate", async () => {
+  const rulesetData = await Ruleset.fromAccountAddress(
+    provider.connection,
+    RULESET_ID,
+  );
+  const mintManagerId = findMintManagerId(mintKeypair.publicKey);
+  const holderAtaId = getAssociatedTokenAddressSync(
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+  );
+
+  const tx = new Transaction();
+  const ix = createSetInUseByInstruction(
+    {
+      mintManager: mintManagerId,
+      ruleset: RULESET_ID,
+      inUseByAddress: inUseByAddress.publicKey,
+      holder: delegate.publicKey,
+      holderTokenAccount: holderAtaId,
+    },
+    {
+      setInUseByIx: {
+        inUseUntil: null,
+        purpose: InUsePurpose.Staked,
+        reference: null,
+      },
+    },
+  );
+  handleRemainingAccountsForRuleset(ix, rulesetData);
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, new Wallet(delegate));
+
+  // check mint manager
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    mintManagerId,
+  );
+  expect(mintManager.inUseBy?.toString()).toBe(
+    inUseByAddress.publicKey.toString(),
+  );
+  expect(mintManager.inUsePurpose).toBe(InUsePurpose.Staked);
+});
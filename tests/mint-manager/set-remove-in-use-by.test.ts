@@ -3,7 +3,11 @@ import { Wallet } from "@project-serum/anchor";
 import { getAssociatedTokenAddressSync, getMint } from "@solana/spl-token";
 import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
 
-import { handleRemainingAccountsForRuleset, Ruleset } from "../../sdk";
+import {
+  handleRemainingAccountsForRuleset,
+  InUsePurpose,
+  Ruleset,
+} from "../../sdk";
 import { MintManager } from "../../sdk/generated/accounts/MintManager";
 import { createRemoveInUseByInstruction } from "../../sdk/generated/instructions/RemoveInUseBy";
 import { createSetInUseByInstruction } from "../../sdk/generated/instructions/SetInUseBy";
@@ -79,13 +83,22 @@ test("Set in use by", async () => {
   );
 
   const tx = new Transaction();
-  const ix = createSetInUseByInstruction({
-    mintManager: mintManagerId,
-    ruleset: RULESET_ID,
-    inUseByAddress: inUseByAddress.publicKey,
-    holder: provider.wallet.publicKey,
-    holderTokenAccount: holderAtaId,
-  });
+  const ix = createSetInUseByInstruction(
+    {
+      mintManager: mintManagerId,
+      ruleset: RULESET_ID,
+      inUseByAddress: inUseByAddress.publicKey,
+      holder: provider.wallet.publicKey,
+      holderTokenAccount: holderAtaId,
+    },
+    {
+      setInUseByIx: {
+        inUseUntil: null,
+        purpose: InUsePurpose.Staked,
+        reference: null,
+      },
+    },
+  );
   handleRemainingAccountsForRuleset(ix, rulesetData);
   tx.add(ix);
   await executeTransaction(provider.connection, tx, provider.wallet);
@@ -99,6 +112,7 @@ test("Set in use by", async () => {
   expect(mintManager.inUseBy?.toString()).toBe(
     inUseByAddress.publicKey.toString(),
   );
+  expect(mintManager.inUsePurpose).toBe(InUsePurpose.Staked);
   expect(mintManager.authority.toString()).toBe(
     provider.wallet.publicKey.toString(),
   );
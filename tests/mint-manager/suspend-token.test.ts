@@ -0,0 +1,143 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import {
+  Keypair,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import {
+  createSuspendTokenInstruction,
+  createTransferInstruction,
+  createUnsuspendTokenInstruction,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+} from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const mintKeypair = Keypair.generate();
+const recipient = Keypair.generate();
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey),
+    provider.wallet,
+  );
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    findRulesetId(RULESET_NAME),
+  );
+  tx.add(
+    createAssociatedTokenAccountInstruction(
+      provider.wallet.publicKey,
+      getAssociatedTokenAddressSync(mintKeypair.publicKey, recipient.publicKey),
+      recipient.publicKey,
+      mintKeypair.publicKey,
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+const suspendTokenTx = (authority = provider.wallet.publicKey) =>
+  new Transaction().add(
+    createSuspendTokenInstruction({
+      mintManager: findMintManagerId(mintKeypair.publicKey),
+      ruleset: findRulesetId(RULESET_NAME),
+      authority,
+    }),
+  );
+
+test("Fail to suspend without the mint manager authority", async () => {
+  const otherAuthority = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      suspendTokenTx(otherAuthority.publicKey),
+      provider.wallet,
+      [otherAuthority],
+    ),
+  ).rejects.toThrow();
+});
+
+test("Suspend token", async () => {
+  await executeTransaction(
+    provider.connection,
+    suspendTokenTx(),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.suspended).toBe(true);
+});
+
+test("Fail to transfer a suspended token", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createTransferInstruction(
+          {
+            mintManager: findMintManagerId(mintKeypair.publicKey),
+            mint: mintKeypair.publicKey,
+            mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+            ruleset: findRulesetId(RULESET_NAME),
+            from: getAssociatedTokenAddressSync(
+              mintKeypair.publicKey,
+              provider.wallet.publicKey,
+            ),
+            to: getAssociatedTokenAddressSync(
+              mintKeypair.publicKey,
+              recipient.publicKey,
+            ),
+            authority: provider.wallet.publicKey,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          },
+          { transferIx: { amount: 1 } },
+        ),
+      ),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Unsuspend token", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createUnsuspendTokenInstruction({
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        authority: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.suspended).toBe(false);
+});
@@ -0,0 +1,64 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { PublicKey, Transaction } from "@solana/web3.js";
+
+import { createSuspendTokenInstruction } from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import { createMigrateMintManagerInstruction } from "../../sdk/generated/instructions/MigrateMintManager";
+import { findMintManagerId, findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { executeTransaction, getProvider } from "../../utils";
+
+// version 0 mint manager loaded from tests/fixtures/legacy-mint-manager-suspend.json
+const LEGACY_MINT = new PublicKey(
+  "Cp84RWn9DVT7RrSM7yffSrPtuAaXK8z7wpFZ5NWSrAK4",
+);
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+});
+
+const suspendTokenTx = () =>
+  new Transaction().add(
+    createSuspendTokenInstruction({
+      mintManager: findMintManagerId(LEGACY_MINT),
+      ruleset: findRulesetId(),
+      authority: provider.wallet.publicKey,
+    }),
+  );
+
+test("Fail to suspend an unmigrated mint manager", async () => {
+  await expect(
+    executeTransaction(provider.connection, suspendTokenTx(), provider.wallet),
+  ).rejects.toThrow();
+  // the version byte follows the discriminator
+  const accountInfo = await provider.connection.getAccountInfo(
+    findMintManagerId(LEGACY_MINT),
+  );
+  expect(accountInfo?.data[8]).toBe(0);
+});
+
+test("Suspend after migration", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createMigrateMintManagerInstruction({
+        mintManager: findMintManagerId(LEGACY_MINT),
+        payer: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    suspendTokenTx(),
+    provider.wallet,
+  );
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(LEGACY_MINT),
+  );
+  expect(mintManager.version).toBe(1);
+  expect(mintManager.suspended).toBe(true);
+});
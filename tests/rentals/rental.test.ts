@@ -0,0 +1,340 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import {
+  createAddInUseLockInstruction,
+  createCancelRentalInstruction,
+  createListRentalInstruction,
+  createMintNewTokenInstruction,
+  createReclaimRentalInstruction,
+  createRecallInstruction,
+  createRentTokenInstruction,
+  InUsePurpose,
+} from "../../sdk";
+import { MintManager } from "../../sdk/generated/accounts/MintManager";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRentalId,
+  findRulesetId,
+  METADATA_PROGRAM_ID,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+  newAccountWithLamports,
+} from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const ROYALTY_RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const mintKeypair = Keypair.generate();
+const royaltyMintKeypair = Keypair.generate();
+const stakingAddress = Keypair.generate();
+
+let provider: SolanaProvider;
+let renter: Keypair;
+
+const ataFor = (mint: PublicKey, owner: PublicKey) =>
+  getAssociatedTokenAddressSync(mint, owner, true, TOKEN_2022_PROGRAM_ID);
+
+const mintNewTokenTx = (mint: Keypair, rulesetName: string) =>
+  new Transaction().add(
+    createMintNewTokenInstruction(
+      {
+        mintManager: findMintManagerId(mint.publicKey),
+        mint: mint.publicKey,
+        mintMetadata: findMintMetadataId(mint.publicKey),
+        ruleset: findRulesetId(rulesetName),
+        targetTokenAccount: ataFor(mint.publicKey, provider.wallet.publicKey),
+        target: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+      },
+      {
+        mintNewTokenIx: {
+          name: "TEST",
+          symbol: "TST",
+          uri: "",
+          sellerFeeBasisPoints: 500,
+          permanentDelegate: true,
+        },
+      },
+    ),
+  );
+
+const listRentalTx = (mint: PublicKey, rulesetName: string) =>
+  new Transaction().add(
+    createListRentalInstruction(
+      {
+        rental: findRentalId(mint),
+        mintManager: findMintManagerId(mint),
+        mint,
+        ruleset: findRulesetId(rulesetName),
+        ownerTokenAccount: ataFor(mint, provider.wallet.publicKey),
+        owner: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      },
+      {
+        listRentalIx: { price: LAMPORTS_PER_SOL / 100, durationSeconds: 1 },
+      },
+    ),
+  );
+
+const recallTx = (from: PublicKey) =>
+  new Transaction().add(
+    createRecallInstruction(
+      {
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        ruleset: findRulesetId(RULESET_NAME),
+        from,
+        to: ataFor(mintKeypair.publicKey, provider.wallet.publicKey),
+        authority: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      },
+      { recallIx: { amount: 1 } },
+    ),
+  );
+
+beforeAll(async () => {
+  provider = await getProvider();
+  renter = await newAccountWithLamports(provider.connection);
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      allowRecall: true,
+    }),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(ROYALTY_RULESET_NAME, provider.wallet.publicKey, {
+      requireRoyaltyPayment: true,
+    }),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    mintNewTokenTx(mintKeypair, RULESET_NAME).add(
+      createAssociatedTokenAccountInstruction(
+        provider.wallet.publicKey,
+        ataFor(mintKeypair.publicKey, renter.publicKey),
+        renter.publicKey,
+        mintKeypair.publicKey,
+        TOKEN_2022_PROGRAM_ID,
+      ),
+    ),
+    provider.wallet,
+    [mintKeypair],
+  );
+  await executeTransaction(
+    provider.connection,
+    mintNewTokenTx(royaltyMintKeypair, ROYALTY_RULESET_NAME),
+    provider.wallet,
+    [royaltyMintKeypair],
+  );
+});
+
+test("List and cancel rental", async () => {
+  await executeTransaction(
+    provider.connection,
+    listRentalTx(mintKeypair.publicKey, RULESET_NAME),
+    provider.wallet,
+  );
+  let mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.inUseBy?.toString()).toBe(
+    findRentalId(mintKeypair.publicKey).toString(),
+  );
+
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createCancelRentalInstruction({
+        rental: findRentalId(mintKeypair.publicKey),
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        ownerTokenAccount: ataFor(
+          mintKeypair.publicKey,
+          provider.wallet.publicKey,
+        ),
+        owner: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      }),
+    ),
+    provider.wallet,
+  );
+  mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.inUseBy).toBeNull();
+  expect(
+    await provider.connection.getAccountInfo(
+      findRentalId(mintKeypair.publicKey),
+    ),
+  ).toBeNull();
+});
+
+test("Fail to recall a listed token", async () => {
+  await executeTransaction(
+    provider.connection,
+    listRentalTx(mintKeypair.publicKey, RULESET_NAME),
+    provider.wallet,
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      recallTx(ataFor(mintKeypair.publicKey, provider.wallet.publicKey)),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Rent token", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createRentTokenInstruction({
+        rental: findRentalId(mintKeypair.publicKey),
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        ruleset: findRulesetId(RULESET_NAME),
+        ownerTokenAccount: ataFor(
+          mintKeypair.publicKey,
+          provider.wallet.publicKey,
+        ),
+        renterTokenAccount: ataFor(mintKeypair.publicKey, renter.publicKey),
+        owner: provider.wallet.publicKey,
+        renter: renter.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      }),
+    ),
+    provider.wallet,
+    [renter],
+  );
+  const renterTokenAccount = await getAccount(
+    provider.connection,
+    ataFor(mintKeypair.publicKey, renter.publicKey),
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  expect(renterTokenAccount.amount.toString()).toBe("1");
+  expect(renterTokenAccount.isFrozen).toBe(true);
+});
+
+test("Fail to recall a rented token", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      recallTx(ataFor(mintKeypair.publicKey, renter.publicKey)),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Renter adds a lock of its own", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createAddInUseLockInstruction(
+        {
+          mintManager: findMintManagerId(mintKeypair.publicKey),
+          ruleset: findRulesetId(RULESET_NAME),
+          inUseByAddress: stakingAddress.publicKey,
+          holder: renter.publicKey,
+          holderTokenAccount: ataFor(mintKeypair.publicKey, renter.publicKey),
+          payer: renter.publicKey,
+        },
+        {
+          addInUseLockIx: {
+            inUseUntil: null,
+            purpose: InUsePurpose.Staked,
+            reference: null,
+          },
+        },
+      ),
+    ),
+    provider.wallet,
+    [renter],
+  );
+});
+
+test("Reclaim rental after expiration", async () => {
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createReclaimRentalInstruction({
+        rental: findRentalId(mintKeypair.publicKey),
+        mintManager: findMintManagerId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        ownerTokenAccount: ataFor(
+          mintKeypair.publicKey,
+          provider.wallet.publicKey,
+        ),
+        renterTokenAccount: ataFor(mintKeypair.publicKey, renter.publicKey),
+        owner: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      }),
+    ),
+    provider.wallet,
+  );
+  const ownerTokenAccount = await getAccount(
+    provider.connection,
+    ataFor(mintKeypair.publicKey, provider.wallet.publicKey),
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  expect(ownerTokenAccount.amount.toString()).toBe("1");
+  const renterTokenAccount = await getAccount(
+    provider.connection,
+    ataFor(mintKeypair.publicKey, renter.publicKey),
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  expect(renterTokenAccount.amount.toString()).toBe("0");
+  expect(renterTokenAccount.isFrozen).toBe(true);
+  // only the rental lock is released
+  const mintManager = await MintManager.fromAccountAddress(
+    provider.connection,
+    findMintManagerId(mintKeypair.publicKey),
+  );
+  expect(mintManager.inUseBy).toBeNull();
+  expect(mintManager.additionalInUseLocks.length).toBe(1);
+  expect(mintManager.additionalInUseLocks[0]!.inUseBy.toString()).toBe(
+    stakingAddress.publicKey.toString(),
+  );
+});
+
+test("Fail to list a token whose ruleset requires royalty payment", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      listRentalTx(royaltyMintKeypair.publicKey, ROYALTY_RULESET_NAME),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
@@ -0,0 +1,93 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import { Ruleset, RulesetCategory } from "../../sdk";
+import { createCloneRulesetInstruction } from "../../sdk/generated/instructions/CloneRuleset";
+import { findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createRulesetTx, executeTransaction, getProvider } from "../../utils";
+
+const SOURCE_RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const CLONE_RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const cloneAuthority = Keypair.generate();
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  const tx = createRulesetTx(SOURCE_RULESET_NAME, provider.wallet.publicKey, {
+    allowedPrograms: [Keypair.generate().publicKey],
+    disallowedAddresses: [Keypair.generate().publicKey],
+    approvedCreators: [Keypair.generate().publicKey],
+    uri: "https://example.com/ruleset.json",
+    descriptionHash: Array(32).fill(7),
+    category: RulesetCategory.RoyaltyEnforcing,
+    minimumCreatorBasisPoints: 500,
+    requiredCreators: [Keypair.generate().publicKey],
+    requireRoyaltyPayment: true,
+    paymentMints: [Keypair.generate().publicKey],
+    preTransferInstructions: [
+      {
+        programId: Keypair.generate().publicKey,
+        discriminator: Buffer.from([1, 2, 3, 4, 5, 6, 7, 8]),
+      },
+    ],
+    allowRecall: true,
+    disallowSuspension: true,
+    inUseReleaseGracePeriodSeconds: 3600,
+    allowAuthorityRemoveInUseBy: true,
+  });
+  await executeTransaction(provider.connection, tx, provider.wallet);
+});
+
+test("Clone ruleset", async () => {
+  const tx = new Transaction().add(
+    createCloneRulesetInstruction(
+      {
+        sourceRuleset: findRulesetId(SOURCE_RULESET_NAME),
+        ruleset: findRulesetId(CLONE_RULESET_NAME),
+        authority: cloneAuthority.publicKey,
+        payer: provider.wallet.publicKey,
+      },
+      { cloneRulesetIx: { name: CLONE_RULESET_NAME } },
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    cloneAuthority,
+  ]);
+
+  const sourceRuleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(SOURCE_RULESET_NAME),
+  );
+  const ruleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(CLONE_RULESET_NAME),
+  );
+  expect(ruleset.name).toBe(CLONE_RULESET_NAME);
+  expect(ruleset.authority.toString()).toBe(
+    cloneAuthority.publicKey.toString(),
+  );
+  expect({
+    ...ruleset.pretty(),
+    name: SOURCE_RULESET_NAME,
+    authority: provider.wallet.publicKey.toString(),
+  }).toEqual(sourceRuleset.pretty());
+});
+
+test("Fail to clone into an existing ruleset", async () => {
+  const tx = new Transaction().add(
+    createCloneRulesetInstruction(
+      {
+        sourceRuleset: findRulesetId(SOURCE_RULESET_NAME),
+        ruleset: findRulesetId(CLONE_RULESET_NAME),
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+      },
+      { cloneRulesetIx: { name: CLONE_RULESET_NAME } },
+    ),
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet),
+  ).rejects.toThrow();
+});
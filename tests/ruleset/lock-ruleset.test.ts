@@ -0,0 +1,142 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import {
+  createLockRulesetInstruction,
+  createMigrateRulesetInstruction,
+  createUpdateRulesetInstruction,
+  Ruleset,
+  RulesetCategory,
+} from "../../sdk";
+import { findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createRulesetTx, executeTransaction, getProvider } from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+// version 0 ruleset loaded from tests/fixtures/legacy-ruleset.json
+const LEGACY_RULESET_NAME = "legacy-ruleset";
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey),
+    provider.wallet,
+  );
+});
+
+test("Fail to lock without the ruleset authority", async () => {
+  const otherAuthority = Keypair.generate();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createLockRulesetInstruction({
+          ruleset: findRulesetId(RULESET_NAME),
+          authority: otherAuthority.publicKey,
+        }),
+      ),
+      provider.wallet,
+      [otherAuthority],
+    ),
+  ).rejects.toThrow();
+});
+
+test("Lock ruleset", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createLockRulesetInstruction({
+        ruleset: findRulesetId(RULESET_NAME),
+        authority: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  const ruleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(RULESET_NAME),
+  );
+  expect(ruleset.locked).toBe(true);
+});
+
+test("Fail to update a locked ruleset", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createUpdateRulesetInstruction(
+          {
+            ruleset: findRulesetId(RULESET_NAME),
+            authority: provider.wallet.publicKey,
+            payer: provider.wallet.publicKey,
+          },
+          {
+            updateRulesetIx: {
+              authority: provider.wallet.publicKey,
+              allowedPrograms: [],
+              disallowedAddresses: [Keypair.generate().publicKey],
+              extensions: [],
+              requireAttachmentApproval: false,
+              approvedCreators: [],
+              uri: "",
+              descriptionHash: Array(32).fill(0),
+              category: RulesetCategory.Unspecified,
+              minimumCreatorBasisPoints: 0,
+              requiredCreators: [],
+              requireRoyaltyPayment: false,
+              paymentMints: [],
+              preTransferInstructions: [],
+              postTransferInstructions: [],
+              allowRecall: false,
+              disallowSuspension: false,
+              inUseReleaseGracePeriodSeconds: null,
+              allowAuthorityRemoveInUseBy: false,
+            },
+          },
+        ),
+      ),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Fail to lock an unmigrated ruleset", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(
+        createLockRulesetInstruction({
+          ruleset: findRulesetId(LEGACY_RULESET_NAME),
+          authority: provider.wallet.publicKey,
+        }),
+      ),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
+
+test("Lock ruleset after migration", async () => {
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      createMigrateRulesetInstruction({
+        ruleset: findRulesetId(LEGACY_RULESET_NAME),
+        payer: provider.wallet.publicKey,
+      }),
+      createLockRulesetInstruction({
+        ruleset: findRulesetId(LEGACY_RULESET_NAME),
+        authority: provider.wallet.publicKey,
+      }),
+    ),
+    provider.wallet,
+  );
+  const ruleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(LEGACY_RULESET_NAME),
+  );
+  expect(ruleset.version).toBe(1);
+  expect(ruleset.locked).toBe(true);
+});
@@ -0,0 +1,50 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair, Transaction } from "@solana/web3.js";
+
+import { Ruleset } from "../../sdk";
+import { createMigrateRulesetInstruction } from "../../sdk/generated/instructions/MigrateRuleset";
+import { findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { executeTransaction, getProvider } from "../../utils";
+
+const RULESET_ID = findRulesetId();
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+});
+
+test("Migrate ruleset", async () => {
+  const tx = new Transaction().add(
+    createMigrateRulesetInstruction({
+      ruleset: RULESET_ID,
+      payer: provider.wallet.publicKey,
+    }),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const ruleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    RULESET_ID,
+  );
+  expect(ruleset.version).toBe(1);
+  expect(ruleset.locked).toBe(false);
+  expect(ruleset.requireRoyaltyPayment).toBe(false);
+});
+
+test("Fail to migrate ruleset without payer signature", async () => {
+  const payer = Keypair.generate();
+  const ix = createMigrateRulesetInstruction({
+    ruleset: RULESET_ID,
+    payer: payer.publicKey,
+  });
+  ix.keys[1]!.isSigner = false;
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(ix),
+      provider.wallet,
+    ),
+  ).rejects.toThrow();
+});
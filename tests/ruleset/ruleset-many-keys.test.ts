@@ -0,0 +1,67 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { Keypair } from "@solana/web3.js";
+
+import { Ruleset } from "../../sdk";
+import { findRulesetId } from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import { createRulesetTx, executeTransaction, getProvider } from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const PROGRAMS_RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const approvedCreators = [...Array(8)].map(() => Keypair.generate().publicKey);
+const requiredCreators = [...Array(8)].map(() => Keypair.generate().publicKey);
+const paymentMints = [...Array(8)].map(() => Keypair.generate().publicKey);
+const allowedPrograms = [...Array(12)].map(() => Keypair.generate().publicKey);
+const disallowedAddresses = [...Array(12)].map(
+  () => Keypair.generate().publicKey,
+);
+
+let provider: SolanaProvider;
+
+beforeAll(async () => {
+  provider = await getProvider();
+});
+
+test("Init ruleset with many keys", async () => {
+  const tx = createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+    approvedCreators,
+    requiredCreators,
+    paymentMints,
+  });
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const ruleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(RULESET_NAME),
+  );
+  expect(ruleset.approvedCreators.map((c) => c.toString())).toEqual(
+    approvedCreators.map((c) => c.toString()),
+  );
+  expect(ruleset.requiredCreators.map((c) => c.toString())).toEqual(
+    requiredCreators.map((c) => c.toString()),
+  );
+  expect(ruleset.paymentMints.map((c) => c.toString())).toEqual(
+    paymentMints.map((c) => c.toString()),
+  );
+});
+
+test("Init ruleset with many program and address keys", async () => {
+  const tx = createRulesetTx(
+    PROGRAMS_RULESET_NAME,
+    provider.wallet.publicKey,
+    { allowedPrograms, disallowedAddresses },
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const ruleset = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(PROGRAMS_RULESET_NAME),
+  );
+  expect(ruleset.name).toBe(PROGRAMS_RULESET_NAME);
+  expect(ruleset.allowedPrograms.map((c) => c.toString())).toEqual(
+    allowedPrograms.map((c) => c.toString()),
+  );
+  expect(ruleset.disallowedAddresses.map((c) => c.toString())).toEqual(
+    disallowedAddresses.map((c) => c.toString()),
+  );
+});
@@ -0,0 +1,17 @@
+import { Transaction } from "@solana/web3.js";
+
+import { createMigrateRulesetInstruction } from "../sdk/generated";
+import { findRulesetId } from "../sdk/pda";
+import { executeTransaction, getProvider } from "../utils";
+
+// the default ruleset is cloned from devnet and may predate the current layout
+export default async () => {
+  const provider = await getProvider();
+  const tx = new Transaction().add(
+    createMigrateRulesetInstruction({
+      ruleset: findRulesetId(),
+      payer: provider.wallet.publicKey,
+    }),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+};
@@ -0,0 +1,173 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import {
+  createMintNewTokenInstruction,
+  createSetInUseByInstruction,
+  createTransferByInUserInstruction,
+  InUsePurpose,
+} from "../../sdk";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+  METADATA_PROGRAM_ID,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+  newAccountWithLamports,
+} from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const ROYALTY_RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const mintKeypair = Keypair.generate();
+const royaltyMintKeypair = Keypair.generate();
+const recipient = Keypair.generate();
+
+let provider: SolanaProvider;
+let inUseByAddress: Keypair;
+
+const ataFor = (mint: PublicKey, owner: PublicKey) =>
+  getAssociatedTokenAddressSync(mint, owner, true, TOKEN_2022_PROGRAM_ID);
+
+// mints a permanent delegate token to the wallet and puts it in use by the in use address
+const mintInUseTokenTx = (mint: Keypair, rulesetName: string) =>
+  new Transaction().add(
+    createMintNewTokenInstruction(
+      {
+        mintManager: findMintManagerId(mint.publicKey),
+        mint: mint.publicKey,
+        mintMetadata: findMintMetadataId(mint.publicKey),
+        ruleset: findRulesetId(rulesetName),
+        targetTokenAccount: ataFor(mint.publicKey, provider.wallet.publicKey),
+        target: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+      },
+      {
+        mintNewTokenIx: {
+          name: "TEST",
+          symbol: "TST",
+          uri: "",
+          sellerFeeBasisPoints: 500,
+          permanentDelegate: true,
+        },
+      },
+    ),
+    createSetInUseByInstruction(
+      {
+        mintManager: findMintManagerId(mint.publicKey),
+        ruleset: findRulesetId(rulesetName),
+        inUseByAddress: inUseByAddress.publicKey,
+        holder: provider.wallet.publicKey,
+        holderTokenAccount: ataFor(mint.publicKey, provider.wallet.publicKey),
+      },
+      {
+        setInUseByIx: {
+          inUseUntil: null,
+          purpose: InUsePurpose.Staked,
+          reference: null,
+        },
+      },
+    ),
+    createAssociatedTokenAccountInstruction(
+      provider.wallet.publicKey,
+      ataFor(mint.publicKey, recipient.publicKey),
+      recipient.publicKey,
+      mint.publicKey,
+      TOKEN_2022_PROGRAM_ID,
+    ),
+  );
+
+const transferByInUserTx = (mint: PublicKey, rulesetName: string) =>
+  new Transaction().add(
+    createTransferByInUserInstruction(
+      {
+        mintManager: findMintManagerId(mint),
+        mint,
+        ruleset: findRulesetId(rulesetName),
+        from: ataFor(mint, provider.wallet.publicKey),
+        to: ataFor(mint, recipient.publicKey),
+        recipient: recipient.publicKey,
+        inUseByAddress: inUseByAddress.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      },
+      { transferByInUserIx: { amount: 1 } },
+    ),
+  );
+
+beforeAll(async () => {
+  provider = await getProvider();
+  inUseByAddress = await newAccountWithLamports(provider.connection);
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(ROYALTY_RULESET_NAME, provider.wallet.publicKey, {
+      requireRoyaltyPayment: true,
+    }),
+    provider.wallet,
+  );
+  await executeTransaction(
+    provider.connection,
+    mintInUseTokenTx(mintKeypair, RULESET_NAME),
+    provider.wallet,
+    [mintKeypair],
+  );
+  await executeTransaction(
+    provider.connection,
+    mintInUseTokenTx(royaltyMintKeypair, ROYALTY_RULESET_NAME),
+    provider.wallet,
+    [royaltyMintKeypair],
+  );
+});
+
+test("Transfer by in use address", async () => {
+  await executeTransaction(
+    provider.connection,
+    transferByInUserTx(mintKeypair.publicKey, RULESET_NAME),
+    provider.wallet,
+    [inUseByAddress],
+  );
+  const recipientTokenAccount = await getAccount(
+    provider.connection,
+    ataFor(mintKeypair.publicKey, recipient.publicKey),
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  expect(recipientTokenAccount.amount.toString()).toBe("1");
+  expect(recipientTokenAccount.isFrozen).toBe(true);
+});
+
+test("Fail to transfer by in use address when the ruleset requires royalty payment", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      transferByInUserTx(royaltyMintKeypair.publicKey, ROYALTY_RULESET_NAME),
+      provider.wallet,
+      [inUseByAddress],
+    ),
+  ).rejects.toThrow();
+});
@@ -140,16 +140,19 @@ test("Transfer", async () => {
   expect(fromAta.mint.toString()).toBe(mintKeypair.publicKey.toString());
   expect(fromAta.amount.toString()).toBe("1");
 
-  const ix = createTransferInstruction({
-    mintManager: mintManagerId,
-    mint: mintKeypair.publicKey,
-    mintMetadata: mintMetadataId,
-    ruleset: RULESET_ID,
-    from: fromAtaId,
-    to: toAtaId,
-    authority: delegate.publicKey,
-    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-  });
+  const ix = createTransferInstruction(
+    {
+      mintManager: mintManagerId,
+      mint: mintKeypair.publicKey,
+      mintMetadata: mintMetadataId,
+      ruleset: RULESET_ID,
+      from: fromAtaId,
+      to: toAtaId,
+      authority: delegate.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    },
+    { transferIx: { amount: 1 } },
+  );
   tx.add(
     createAssociatedTokenAccountInstruction(
       delegate.publicKey,
@@ -102,16 +102,19 @@ test("Transfer", async () => {
       mintKeypair.publicKey,
     ),
   );
-  const ix = createTransferInstruction({
-    mintManager: mintManagerId,
-    mint: mintKeypair.publicKey,
-    mintMetadata: mintMetadataId,
-    ruleset: RULESET_ID,
-    from: fromAtaId,
-    to: toAtaId,
-    authority: provider.wallet.publicKey,
-    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-  });
+  const ix = createTransferInstruction(
+    {
+      mintManager: mintManagerId,
+      mint: mintKeypair.publicKey,
+      mintMetadata: mintMetadataId,
+      ruleset: RULESET_ID,
+      from: fromAtaId,
+      to: toAtaId,
+      authority: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    },
+    { transferIx: { amount: 1 } },
+  );
   handleRemainingAccountsForRuleset(ix, rulesetData);
   tx.add(ix);
   await executeTransaction(provider.connection, tx, provider.wallet);
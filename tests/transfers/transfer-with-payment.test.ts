@@ -0,0 +1,125 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  createAssociatedTokenAccountInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import { handleRemainingAccountsForRuleset, Ruleset } from "../../sdk";
+import { createTransferWithPaymentInstruction } from "../../sdk/generated/instructions/TransferWithPayment";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  executeTransaction,
+  getProvider,
+  newAccountWithLamports,
+} from "../../utils";
+
+const mintKeypair = Keypair.generate();
+
+const RULESET_ID = findRulesetId();
+
+let provider: SolanaProvider;
+let buyer: Keypair;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  buyer = await newAccountWithLamports(provider.connection);
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    RULESET_ID,
+  );
+  tx.add(
+    createAssociatedTokenAccountInstruction(
+      provider.wallet.publicKey,
+      getAssociatedTokenAddressSync(mintKeypair.publicKey, buyer.publicKey),
+      buyer.publicKey,
+      mintKeypair.publicKey,
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+const transferWithPaymentTx = async (
+  price: number,
+  to: PublicKey,
+): Promise<Transaction> => {
+  const rulesetData = await Ruleset.fromAccountAddress(
+    provider.connection,
+    RULESET_ID,
+  );
+  const ix = createTransferWithPaymentInstruction(
+    {
+      mintManager: findMintManagerId(mintKeypair.publicKey),
+      mint: mintKeypair.publicKey,
+      mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+      ruleset: RULESET_ID,
+      from: getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        provider.wallet.publicKey,
+      ),
+      to,
+      authority: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      buyer: buyer.publicKey,
+    },
+    { transferWithPaymentIx: { price, amount: 1 } },
+  );
+  handleRemainingAccountsForRuleset(ix, rulesetData);
+  // the metadata creator receives the royalty
+  ix.keys.push({
+    pubkey: provider.wallet.publicKey,
+    isWritable: true,
+    isSigner: false,
+  });
+  return new Transaction().add(ix);
+};
+
+test("Fail to transfer with a zero price", async () => {
+  const tx = await transferWithPaymentTx(
+    0,
+    getAssociatedTokenAddressSync(mintKeypair.publicKey, buyer.publicKey),
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet, [buyer]),
+  ).rejects.toThrow();
+});
+
+test("Transfer with payment", async () => {
+  const toAtaId = getAssociatedTokenAddressSync(
+    mintKeypair.publicKey,
+    buyer.publicKey,
+  );
+  const buyerLamportsBefore = await provider.connection.getBalance(
+    buyer.publicKey,
+  );
+  const tx = await transferWithPaymentTx(LAMPORTS_PER_SOL, toAtaId);
+  await executeTransaction(provider.connection, tx, provider.wallet, [buyer]);
+
+  // 500 seller fee basis points of the price
+  const buyerLamportsAfter = await provider.connection.getBalance(
+    buyer.publicKey,
+  );
+  expect(buyerLamportsBefore - buyerLamportsAfter).toBe(
+    LAMPORTS_PER_SOL * 0.05,
+  );
+  const toAta = await getAccount(provider.connection, toAtaId);
+  expect(toAta.amount.toString()).toBe("1");
+  expect(toAta.isFrozen).toBe(true);
+});
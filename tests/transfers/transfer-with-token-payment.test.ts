@@ -0,0 +1,185 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+
+import { handleRemainingAccountsForRuleset, Ruleset } from "../../sdk";
+import { createTransferWithTokenPaymentInstruction } from "../../sdk/generated/instructions/TransferWithTokenPayment";
+import {
+  findMintManagerId,
+  findMintMetadataId,
+  findRulesetId,
+} from "../../sdk/pda";
+import type { SolanaProvider } from "../../utils";
+import {
+  createCCSMintTx,
+  createMintTx,
+  createRulesetTx,
+  executeTransaction,
+  getProvider,
+  newAccountWithLamports,
+} from "../../utils";
+
+const RULESET_NAME = `ruleset-${Math.random().toString(36).slice(2, 10)}`;
+const mintKeypair = Keypair.generate();
+const paymentMintKeypair = Keypair.generate();
+const otherPaymentMintKeypair = Keypair.generate();
+
+let provider: SolanaProvider;
+let buyer: Keypair;
+
+beforeAll(async () => {
+  provider = await getProvider();
+  buyer = await newAccountWithLamports(provider.connection);
+  for (const paymentMint of [paymentMintKeypair, otherPaymentMintKeypair]) {
+    const tx = await createMintTx(
+      provider.connection,
+      paymentMint.publicKey,
+      provider.wallet.publicKey,
+    );
+    tx.add(
+      createAssociatedTokenAccountInstruction(
+        provider.wallet.publicKey,
+        getAssociatedTokenAddressSync(paymentMint.publicKey, buyer.publicKey),
+        buyer.publicKey,
+        paymentMint.publicKey,
+      ),
+      createMintToInstruction(
+        paymentMint.publicKey,
+        getAssociatedTokenAddressSync(paymentMint.publicKey, buyer.publicKey),
+        provider.wallet.publicKey,
+        1000,
+      ),
+    );
+    await executeTransaction(provider.connection, tx, provider.wallet, [
+      paymentMint,
+    ]);
+  }
+
+  await executeTransaction(
+    provider.connection,
+    createRulesetTx(RULESET_NAME, provider.wallet.publicKey, {
+      requireRoyaltyPayment: true,
+      paymentMints: [paymentMintKeypair.publicKey],
+    }),
+    provider.wallet,
+  );
+  const tx = await createCCSMintTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey,
+    findRulesetId(RULESET_NAME),
+  );
+  tx.add(
+    createAssociatedTokenAccountInstruction(
+      provider.wallet.publicKey,
+      getAssociatedTokenAddressSync(mintKeypair.publicKey, buyer.publicKey),
+      buyer.publicKey,
+      mintKeypair.publicKey,
+    ),
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet, [
+    mintKeypair,
+  ]);
+});
+
+const transferWithTokenPaymentTx = async (
+  paymentMint: PublicKey,
+): Promise<Transaction> => {
+  const rulesetData = await Ruleset.fromAccountAddress(
+    provider.connection,
+    findRulesetId(RULESET_NAME),
+  );
+  const ix = createTransferWithTokenPaymentInstruction(
+    {
+      mintManager: findMintManagerId(mintKeypair.publicKey),
+      mint: mintKeypair.publicKey,
+      mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+      ruleset: findRulesetId(RULESET_NAME),
+      from: getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        provider.wallet.publicKey,
+      ),
+      to: getAssociatedTokenAddressSync(mintKeypair.publicKey, buyer.publicKey),
+      authority: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      buyer: buyer.publicKey,
+      paymentMint,
+      buyerTokenAccount: getAssociatedTokenAddressSync(
+        paymentMint,
+        buyer.publicKey,
+      ),
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+    },
+    { transferWithTokenPaymentIx: { price: 1000, amount: 1 } },
+  );
+  handleRemainingAccountsForRuleset(ix, rulesetData);
+  // the metadata creator and its payment token account receive the royalty
+  ix.keys.push(
+    {
+      pubkey: provider.wallet.publicKey,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: getAssociatedTokenAddressSync(
+        paymentMint,
+        provider.wallet.publicKey,
+      ),
+      isWritable: true,
+      isSigner: false,
+    },
+  );
+  return new Transaction().add(ix);
+};
+
+test("Fail to pay with a mint the ruleset does not allow", async () => {
+  const tx = await transferWithTokenPaymentTx(
+    otherPaymentMintKeypair.publicKey,
+  );
+  await expect(
+    executeTransaction(provider.connection, tx, provider.wallet, [buyer]),
+  ).rejects.toThrow();
+});
+
+test("Transfer with token payment", async () => {
+  const tx = await transferWithTokenPaymentTx(paymentMintKeypair.publicKey);
+  await executeTransaction(provider.connection, tx, provider.wallet, [buyer]);
+
+  // 500 seller fee basis points of the price
+  const buyerPaymentAccount = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(
+      paymentMintKeypair.publicKey,
+      buyer.publicKey,
+    ),
+  );
+  expect(buyerPaymentAccount.amount.toString()).toBe("950");
+  const creatorPaymentAccount = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(
+      paymentMintKeypair.publicKey,
+      provider.wallet.publicKey,
+    ),
+  );
+  // on top of the token createMintTx minted to the creator
+  expect(creatorPaymentAccount.amount.toString()).toBe("51");
+  const toAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(mintKeypair.publicKey, buyer.publicKey),
+  );
+  expect(toAta.amount.toString()).toBe("1");
+  expect(toAta.isFrozen).toBe(true);
+});
@@ -10,7 +10,7 @@ import { findAta } from "@solana-nft-programs/common";
 import dotenv from "dotenv";
 import fetch from "node-fetch";
 
-import { createInitMintManagerInstruction } from "../sdk";
+import { createInitMintManagerInstruction, MintManagerKind } from "../sdk";
 import {
   findMintManagerId,
   findMintMetadataId,
@@ -98,16 +98,19 @@ const main = async (params: CreateCCSTokenParams, cluster = "devnet") => {
 
   // init mint manager
   tx.add(
-    createInitMintManagerInstruction({
-      mintManager: mintManagerId,
-      mintMetadata: findMintMetadataId(mintKeypair.publicKey),
-      mint: mintKeypair.publicKey,
-      ruleset: rulesetId,
-      holderTokenAccount: holdetAta,
-      tokenAuthority: wallet.publicKey,
-      authority: wallet.publicKey,
-      payer: wallet.publicKey,
-    }),
+    createInitMintManagerInstruction(
+      {
+        mintManager: mintManagerId,
+        mintMetadata: findMintMetadataId(mintKeypair.publicKey),
+        mint: mintKeypair.publicKey,
+        ruleset: rulesetId,
+        holderTokenAccount: holdetAta,
+        tokenAuthority: wallet.publicKey,
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+      },
+      { initMintManagerIx: { kind: MintManagerKind.NonFungible } },
+    ),
   );
 
   let txid = "";
@@ -2,7 +2,7 @@ import * as anchor from "@project-serum/anchor";
 import { Keypair, Transaction } from "@solana/web3.js";
 import dotenv from "dotenv";
 
-import { Ruleset } from "../sdk";
+import { Ruleset, RulesetCategory } from "../sdk";
 import { createInitRulesetInstruction } from "../sdk/generated/instructions/InitRuleset";
 import { findRulesetId } from "../sdk/pda";
 import { connectionFor, executeTransaction } from "../utils";
@@ -35,6 +35,21 @@ const main = async (params: RulesetParams, cluster = "devnet") => {
           disallowedAddresses: [],
           allowedPrograms: [],
           extensions: [],
+          requireAttachmentApproval: false,
+          approvedCreators: [],
+          uri: "",
+          descriptionHash: Array(32).fill(0),
+          category: RulesetCategory.Unspecified,
+          minimumCreatorBasisPoints: 0,
+          requiredCreators: [],
+          requireRoyaltyPayment: false,
+          paymentMints: [],
+          preTransferInstructions: [],
+          postTransferInstructions: [],
+          allowRecall: false,
+          disallowSuspension: false,
+          inUseReleaseGracePeriodSeconds: null,
+          allowAuthorityRemoveInUseBy: false,
         },
       },
     ),
@@ -1,3 +1,8 @@
+import {
+  CreateMetadataV2,
+  Creator,
+  DataV2,
+} from "@metaplex-foundation/mpl-token-metadata";
 import { utils, Wallet } from "@project-serum/anchor";
 import {
   createAssociatedTokenAccountInstruction,
@@ -21,10 +26,14 @@ import {
 import { findAta } from "@solana-nft-programs/common";
 import dotenv from "dotenv";
 
-import { findMintManagerId, findMintMetadataId } from "./sdk";
+import { findMintManagerId, findMintMetadataId, findRulesetId } from "./sdk";
+import type { InitRulesetIx } from "./sdk/generated";
 import {
   createInitMintManagerInstruction,
+  createInitRulesetInstruction,
+  MintManagerKind,
   PROGRAM_ADDRESS,
+  RulesetCategory,
 } from "./sdk/generated";
 
 dotenv.config();
@@ -198,6 +207,37 @@ export const createMintTx = async (
   );
 };
 
+export const createMintMetadataTx = (
+  mint: PublicKey,
+  authority: PublicKey,
+  creators: Creator[] = [
+    new Creator({
+      address: authority.toString(),
+      verified: true,
+      share: 100,
+    }),
+  ],
+): Transaction => {
+  return new CreateMetadataV2(
+    { feePayer: authority },
+    {
+      metadata: findMintMetadataId(mint),
+      metadataData: new DataV2({
+        name: "TEST",
+        symbol: "TST",
+        uri: "",
+        sellerFeeBasisPoints: 500,
+        creators,
+        collection: null,
+        uses: null,
+      }),
+      updateAuthority: authority,
+      mint,
+      mintAuthority: authority,
+    },
+  );
+};
+
 export const createCCSMintTx = async (
   connection: Connection,
   mint: PublicKey,
@@ -205,24 +245,68 @@ export const createCCSMintTx = async (
   rulesetId: PublicKey,
 ): Promise<Transaction> => {
   const tx = await createMintTx(connection, mint, authority);
+  tx.add(createMintMetadataTx(mint, authority));
   const mintManagerId = findMintManagerId(mint);
   const mintMetadataId = findMintMetadataId(mint);
   const targetTokenAccountId = await findAta(mint, authority, true);
   tx.add(
-    createInitMintManagerInstruction({
-      mintManager: mintManagerId,
-      mint: mint,
-      mintMetadata: mintMetadataId,
-      ruleset: rulesetId,
-      holderTokenAccount: targetTokenAccountId,
-      tokenAuthority: authority,
-      authority: authority,
-      payer: authority,
-    }),
+    createInitMintManagerInstruction(
+      {
+        mintManager: mintManagerId,
+        mint: mint,
+        mintMetadata: mintMetadataId,
+        ruleset: rulesetId,
+        holderTokenAccount: targetTokenAccountId,
+        tokenAuthority: authority,
+        authority: authority,
+        payer: authority,
+      },
+      { initMintManagerIx: { kind: MintManagerKind.NonFungible } },
+    ),
   );
   return tx;
 };
 
+export const createRulesetTx = (
+  name: string,
+  authority: PublicKey,
+  params: Partial<InitRulesetIx> = {},
+): Transaction => {
+  return new Transaction().add(
+    createInitRulesetInstruction(
+      {
+        ruleset: findRulesetId(name),
+        authority: authority,
+        payer: authority,
+      },
+      {
+        initRulesetIx: {
+          name: name,
+          allowedPrograms: [],
+          disallowedAddresses: [],
+          extensions: [],
+          requireAttachmentApproval: false,
+          approvedCreators: [],
+          uri: "",
+          descriptionHash: Array(32).fill(0),
+          category: RulesetCategory.Unspecified,
+          minimumCreatorBasisPoints: 0,
+          requiredCreators: [],
+          requireRoyaltyPayment: false,
+          paymentMints: [],
+          preTransferInstructions: [],
+          postTransferInstructions: [],
+          allowRecall: false,
+          disallowSuspension: false,
+          inUseReleaseGracePeriodSeconds: null,
+          allowAuthorityRemoveInUseBy: false,
+          ...params,
+        },
+      },
+    ),
+  );
+};
+
 type AccountFn<T> = () => Promise<T>;
 export async function tryGetAccount<T>(fn: AccountFn<T>) {
   try {